@@ -0,0 +1,115 @@
+use std::fmt::{self, Display};
+
+/// A predefined set of grantees and permissions to apply to an object.
+///
+/// Find out more about canned ACLs from the [AWS documentation][docs]
+///
+/// [docs]: https://docs.aws.amazon.com/AmazonS3/latest/userguide/acl-overview.html#canned-acl
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ObjectCannedAcl {
+    Private,
+    PublicRead,
+    PublicReadWrite,
+    AuthenticatedRead,
+    AwsExecRead,
+    BucketOwnerRead,
+    BucketOwnerFullControl,
+}
+
+impl ObjectCannedAcl {
+    /// Convert this `ObjectCannedAcl` into the name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::ObjectCannedAcl;
+    /// assert_eq!(ObjectCannedAcl::PublicRead.to_str(), "public-read");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Private => "private",
+            Self::PublicRead => "public-read",
+            Self::PublicReadWrite => "public-read-write",
+            Self::AuthenticatedRead => "authenticated-read",
+            Self::AwsExecRead => "aws-exec-read",
+            Self::BucketOwnerRead => "bucket-owner-read",
+            Self::BucketOwnerFullControl => "bucket-owner-full-control",
+        }
+    }
+}
+
+impl Display for ObjectCannedAcl {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// A predefined set of grantees and permissions to apply to a bucket.
+///
+/// Find out more about canned ACLs from the [AWS documentation][docs]
+///
+/// [docs]: https://docs.aws.amazon.com/AmazonS3/latest/userguide/acl-overview.html#canned-acl
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BucketCannedAcl {
+    Private,
+    PublicRead,
+    PublicReadWrite,
+    AuthenticatedRead,
+    LogDeliveryWrite,
+}
+
+impl BucketCannedAcl {
+    /// Convert this `BucketCannedAcl` into the name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::BucketCannedAcl;
+    /// assert_eq!(BucketCannedAcl::LogDeliveryWrite.to_str(), "log-delivery-write");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Private => "private",
+            Self::PublicRead => "public-read",
+            Self::PublicReadWrite => "public-read-write",
+            Self::AuthenticatedRead => "authenticated-read",
+            Self::LogDeliveryWrite => "log-delivery-write",
+        }
+    }
+}
+
+impl Display for BucketCannedAcl {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_str() {
+        assert_eq!(ObjectCannedAcl::Private.to_str(), "private");
+        assert_eq!(ObjectCannedAcl::PublicRead.to_str(), "public-read");
+        assert_eq!(
+            ObjectCannedAcl::PublicReadWrite.to_str(),
+            "public-read-write"
+        );
+        assert_eq!(
+            ObjectCannedAcl::AuthenticatedRead.to_str(),
+            "authenticated-read"
+        );
+        assert_eq!(ObjectCannedAcl::AwsExecRead.to_str(), "aws-exec-read");
+        assert_eq!(
+            ObjectCannedAcl::BucketOwnerRead.to_str(),
+            "bucket-owner-read"
+        );
+        assert_eq!(
+            ObjectCannedAcl::BucketOwnerFullControl.to_str(),
+            "bucket-owner-full-control"
+        );
+    }
+}
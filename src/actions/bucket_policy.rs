@@ -0,0 +1,264 @@
+//! Typed bucket policy documents
+
+use std::collections::BTreeMap;
+
+use serde::ser::{SerializeMap as _, Serializer};
+use serde::Serialize;
+
+use crate::Bucket;
+
+/// The version of the policy language used by [`BucketPolicy`]
+const POLICY_VERSION: &str = "2012-10-17";
+
+/// A bucket policy, serialized to the JSON document expected by S3
+///
+/// ```rust
+/// # use rusty_s3::{Bucket, UrlStyle};
+/// # use rusty_s3::actions::BucketPolicy;
+/// # use rusty_s3::actions::bucket_policy::{Effect, Principal, Statement};
+/// # let bucket = Bucket::new("https://s3.amazonaws.com".parse().unwrap(), UrlStyle::VirtualHost, "examplebucket", "us-east-1").unwrap();
+/// let mut statement = Statement::new(Effect::Allow, Principal::Everyone);
+/// statement.with_action("s3:GetObject");
+/// statement.with_objects_resource(&bucket, "*");
+///
+/// let mut policy = BucketPolicy::new();
+/// policy.with_statement(statement);
+/// let json = policy.to_json();
+/// ```
+///
+/// Find out more about bucket policies from the [AWS documentation][docs]
+///
+/// [docs]: https://docs.aws.amazon.com/AmazonS3/latest/userguide/bucket-policies.html
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BucketPolicy {
+    #[serde(rename = "Version")]
+    version: &'static str,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(rename = "Statement")]
+    statements: Vec<Statement>,
+}
+
+impl BucketPolicy {
+    /// Construct an empty `BucketPolicy`, using the `2012-10-17` policy language
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            version: POLICY_VERSION,
+            ..Default::default()
+        }
+    }
+
+    /// Set the `Id` of the policy
+    pub fn with_id(&mut self, id: impl Into<String>) {
+        self.id = Some(id.into());
+    }
+
+    /// Add `statement` to the policy
+    pub fn with_statement(&mut self, statement: Statement) {
+        self.statements.push(statement);
+    }
+
+    /// Serialize the policy into the JSON document to be sent as the body
+    /// of a `PUT ?policy` request
+    ///
+    /// # Panics
+    ///
+    /// Panics if the policy cannot be serialized.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+/// A statement of a [`BucketPolicy`]
+#[derive(Debug, Clone, Serialize)]
+pub struct Statement {
+    #[serde(rename = "Sid", skip_serializing_if = "Option::is_none")]
+    sid: Option<String>,
+    #[serde(rename = "Effect")]
+    effect: Effect,
+    #[serde(rename = "Principal")]
+    principal: Principal,
+    #[serde(rename = "Action")]
+    actions: Vec<String>,
+    #[serde(rename = "Resource")]
+    resources: Vec<String>,
+    #[serde(rename = "Condition", skip_serializing_if = "BTreeMap::is_empty")]
+    conditions: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+}
+
+impl Statement {
+    /// Construct a `Statement` with no actions, resources and conditions
+    #[must_use]
+    pub const fn new(effect: Effect, principal: Principal) -> Self {
+        Self {
+            sid: None,
+            effect,
+            principal,
+            actions: Vec::new(),
+            resources: Vec::new(),
+            conditions: BTreeMap::new(),
+        }
+    }
+
+    /// Set the `Sid` of the statement
+    pub fn with_sid(&mut self, sid: impl Into<String>) {
+        self.sid = Some(sid.into());
+    }
+
+    /// Add `action`, like `s3:GetObject`, to the statement
+    pub fn with_action(&mut self, action: impl Into<String>) {
+        self.actions.push(action.into());
+    }
+
+    /// Add the ARN `resource` to the statement
+    pub fn with_resource(&mut self, resource: impl Into<String>) {
+        self.resources.push(resource.into());
+    }
+
+    /// Add `bucket` itself to the resources of the statement,
+    /// as required by bucket actions like `s3:ListBucket`
+    pub fn with_bucket_resource(&mut self, bucket: &Bucket) {
+        self.resources.push(bucket.arn());
+    }
+
+    /// Add the objects of `bucket` matching `pattern`, like `*` or `photos/*`,
+    /// to the resources of the statement
+    pub fn with_objects_resource(&mut self, bucket: &Bucket, pattern: &str) {
+        self.resources.push(format!("{}/{pattern}", bucket.arn()));
+    }
+
+    /// Add a condition, requiring `key` to match `value` using `operator`
+    ///
+    /// For example `("IpAddress", "aws:SourceIp", "192.0.2.0/24")`.
+    /// Multiple values for the same `operator` and `key` match if any of them does.
+    pub fn with_condition(
+        &mut self,
+        operator: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) {
+        self.conditions
+            .entry(operator.into())
+            .or_default()
+            .entry(key.into())
+            .or_default()
+            .push(value.into());
+    }
+}
+
+/// Whether a [`Statement`] allows or denies access
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+/// The principal a [`Statement`] applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Principal {
+    /// Everyone, including anonymous users, serialized as `"*"`
+    Everyone,
+    /// The AWS accounts, users or roles with the given ARNs or account ids
+    Aws(Vec<String>),
+}
+
+impl Serialize for Principal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Everyone => serializer.serialize_str("*"),
+            Self::Aws(principals) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("AWS", principals)?;
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn public_read() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut statement = Statement::new(Effect::Allow, Principal::Everyone);
+        statement.with_sid("PublicRead");
+        statement.with_action("s3:GetObject");
+        statement.with_objects_resource(&bucket, "*");
+
+        let mut policy = BucketPolicy::new();
+        policy.with_statement(statement);
+
+        let expected = r#"{"Version":"2012-10-17","Statement":[{"Sid":"PublicRead","Effect":"Allow","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::examplebucket/*"]}]}"#;
+        assert_eq!(policy.to_json(), expected);
+    }
+
+    #[test]
+    fn anonymous_list_and_read() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let everyone = || Principal::Aws(vec!["*".to_owned()]);
+        let mut list = Statement::new(Effect::Allow, everyone());
+        list.with_action("s3:ListBucket");
+        list.with_bucket_resource(&bucket);
+        let mut get = Statement::new(Effect::Allow, everyone());
+        get.with_action("s3:GetObject");
+        get.with_objects_resource(&bucket, "*");
+
+        let mut policy = BucketPolicy::new();
+        policy.with_statement(list);
+        policy.with_statement(get);
+
+        let expected = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"AWS":["*"]},"Action":["s3:ListBucket"],"Resource":["arn:aws:s3:::examplebucket"]},{"Effect":"Allow","Principal":{"AWS":["*"]},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::examplebucket/*"]}]}"#;
+        assert_eq!(policy.to_json(), expected);
+    }
+
+    #[test]
+    fn principals_and_conditions() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut statement = Statement::new(
+            Effect::Deny,
+            Principal::Aws(vec!["arn:aws:iam::111122223333:root".to_owned()]),
+        );
+        statement.with_action("s3:ListBucket");
+        statement.with_bucket_resource(&bucket);
+        statement.with_condition("NotIpAddress", "aws:SourceIp", "192.0.2.0/24");
+        statement.with_condition("NotIpAddress", "aws:SourceIp", "203.0.113.0/24");
+
+        let mut policy = BucketPolicy::new();
+        policy.with_id("examplepolicy");
+        policy.with_statement(statement);
+
+        let expected = r#"{"Version":"2012-10-17","Id":"examplepolicy","Statement":[{"Effect":"Deny","Principal":{"AWS":["arn:aws:iam::111122223333:root"]},"Action":["s3:ListBucket"],"Resource":["arn:aws:s3:::examplebucket"],"Condition":{"NotIpAddress":{"aws:SourceIp":["192.0.2.0/24","203.0.113.0/24"]}}}]}"#;
+        assert_eq!(policy.to_json(), expected);
+    }
+}
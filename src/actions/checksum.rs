@@ -0,0 +1,97 @@
+use std::fmt::{self, Display};
+
+/// The checksum algorithm used by S3 to verify the integrity of an object.
+///
+/// Find out more about checksums from the [AWS documentation][docs]
+///
+/// [docs]: https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(serde::Deserialize))]
+#[cfg_attr(feature = "full", serde(rename_all = "UPPERCASE"))]
+pub enum ChecksumAlgorithm {
+    Crc32,
+    Crc32c,
+    Crc64Nvme,
+    Sha1,
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Convert this `ChecksumAlgorithm` into the uppercase name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::ChecksumAlgorithm;
+    /// assert_eq!(ChecksumAlgorithm::Crc32.to_str(), "CRC32");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Crc32 => "CRC32",
+            Self::Crc32c => "CRC32C",
+            Self::Crc64Nvme => "CRC64NVME",
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+        }
+    }
+
+    /// Get the name of the header holding the base64 encoded checksum.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::ChecksumAlgorithm;
+    /// assert_eq!(ChecksumAlgorithm::Crc32.header_name(), "x-amz-checksum-crc32");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn header_name(self) -> &'static str {
+        match self {
+            Self::Crc32 => "x-amz-checksum-crc32",
+            Self::Crc32c => "x-amz-checksum-crc32c",
+            Self::Crc64Nvme => "x-amz-checksum-crc64nvme",
+            Self::Sha1 => "x-amz-checksum-sha1",
+            Self::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+}
+
+impl Display for ChecksumAlgorithm {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_str() {
+        assert_eq!(ChecksumAlgorithm::Crc32.to_str(), "CRC32");
+        assert_eq!(ChecksumAlgorithm::Crc32c.to_str(), "CRC32C");
+        assert_eq!(ChecksumAlgorithm::Crc64Nvme.to_str(), "CRC64NVME");
+        assert_eq!(ChecksumAlgorithm::Sha1.to_str(), "SHA1");
+        assert_eq!(ChecksumAlgorithm::Sha256.to_str(), "SHA256");
+    }
+
+    #[test]
+    fn header_name() {
+        assert_eq!(
+            ChecksumAlgorithm::Crc32.header_name(),
+            "x-amz-checksum-crc32"
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Crc32c.header_name(),
+            "x-amz-checksum-crc32c"
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Crc64Nvme.header_name(),
+            "x-amz-checksum-crc64nvme"
+        );
+        assert_eq!(ChecksumAlgorithm::Sha1.header_name(), "x-amz-checksum-sha1");
+        assert_eq!(
+            ChecksumAlgorithm::Sha256.header_name(),
+            "x-amz-checksum-sha256"
+        );
+    }
+}
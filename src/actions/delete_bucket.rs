@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::time::Duration;
 
 use time::OffsetDateTime;
@@ -5,6 +6,7 @@ use url::Url;
 
 use crate::actions::Method;
 use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
 use crate::signing::sign;
 use crate::{Bucket, Credentials, Map};
 
@@ -18,7 +20,7 @@ use crate::{Bucket, Credentials, Map};
 #[derive(Debug, Clone)]
 pub struct DeleteBucket<'a> {
     bucket: &'a Bucket,
-    credentials: &'a Credentials,
+    credentials: Option<Cow<'a, Credentials>>,
 
     query: Map<'a>,
     headers: Map<'a>,
@@ -26,10 +28,10 @@ pub struct DeleteBucket<'a> {
 
 impl<'a> DeleteBucket<'a> {
     #[must_use]
-    pub const fn new(bucket: &'a Bucket, credentials: &'a Credentials) -> Self {
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
         Self {
             bucket,
-            credentials,
+            credentials: credentials.into_credentials(),
 
             query: Map::new(),
             headers: Map::new(),
@@ -48,21 +50,29 @@ impl<'a> S3Action<'a> for DeleteBucket<'a> {
         &mut self.headers
     }
 
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
     fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
         let url = self.bucket.base_url().clone();
 
-        sign(
-            time,
-            Self::METHOD,
-            url,
-            self.credentials.key(),
-            self.credentials.secret(),
-            self.credentials.token(),
-            self.bucket.region(),
-            expires_in.as_secs(),
-            self.query.iter(),
-            self.headers.iter(),
-        )
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                self.query.iter(),
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, self.query.iter()),
+        }
     }
 }
 
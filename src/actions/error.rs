@@ -0,0 +1,242 @@
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+use std::io::{BufReader, Read};
+
+use serde::Deserialize;
+
+/// The error returned by S3 in the body of a failed request.
+///
+/// Find out more about error responses from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename = "Error")]
+pub struct S3ErrorResponse {
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "Message")]
+    pub message: Option<String>,
+    #[serde(rename = "RequestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "HostId")]
+    pub host_id: Option<String>,
+    #[serde(rename = "Resource")]
+    pub resource: Option<String>,
+    /// The region of the bucket, returned by some region redirects
+    #[serde(rename = "Region")]
+    pub region: Option<String>,
+    /// The endpoint the request should be sent to, returned by region redirects
+    #[serde(rename = "Endpoint")]
+    pub endpoint: Option<String>,
+}
+
+impl S3ErrorResponse {
+    /// Parse the XML error response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse(s: impl AsRef<[u8]>) -> Result<Self, quick_xml::DeError> {
+        Self::parse_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML error response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_from_reader(s: impl Read) -> Result<Self, quick_xml::DeError> {
+        quick_xml::de::from_reader(BufReader::new(s))
+    }
+
+    /// Get the region the request should have been sent to,
+    /// from the `Region` or the `Endpoint` of a redirect
+    ///
+    /// Pass it to [`Bucket::with_region`](crate::Bucket::with_region)
+    /// in order to retry the request.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::S3ErrorResponse;
+    /// let body = "<Error><Code>PermanentRedirect</Code><Endpoint>rusty-s3.s3.eu-west-1.amazonaws.com</Endpoint></Error>";
+    /// let error = S3ErrorResponse::parse(body).unwrap();
+    /// assert_eq!(error.redirect_region(), Some("eu-west-1"));
+    /// ```
+    #[must_use]
+    pub fn redirect_region(&self) -> Option<&str> {
+        if let Some(region) = &self.region {
+            return Some(region);
+        }
+
+        let endpoint = self.endpoint.as_deref()?;
+        let endpoint = endpoint
+            .strip_suffix(".amazonaws.com")
+            .or_else(|| endpoint.strip_suffix(".amazonaws.com.cn"))?;
+        let region = match endpoint.split('.').collect::<Vec<_>>()[..] {
+            [.., "s3", region] | [.., "s3", "dualstack", region] => region,
+            // the legacy global endpoint of `us-east-1`
+            [.., "s3-external-1"] => return Some("us-east-1"),
+            // legacy `s3-<region>` endpoints
+            [.., legacy] => legacy.strip_prefix("s3-")?,
+            [] => return None,
+        };
+        is_region(region).then_some(region)
+    }
+}
+
+/// Whether `s` looks like a region, like `eu-west-1` or `us-gov-west-1`,
+/// as opposed to an endpoint like `accelerate` or `website-us-east-1`
+fn is_region(s: &str) -> bool {
+    let parts = s.split('-').collect::<Vec<_>>();
+    match &parts[..] {
+        [area, names @ .., number] if !names.is_empty() => {
+            area.len() == 2
+                && area.bytes().all(|b| b.is_ascii_lowercase())
+                && names
+                    .iter()
+                    .all(|name| !name.is_empty() && name.bytes().all(|b| b.is_ascii_lowercase()))
+                && !number.is_empty()
+                && number.bytes().all(|b| b.is_ascii_digit())
+        }
+        _ => false,
+    }
+}
+
+impl Display for S3ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{}: {}", self.code, message),
+            None => f.write_str(&self.code),
+        }
+    }
+}
+
+impl StdError for S3ErrorResponse {}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_access_denied() {
+        let input = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>AccessDenied</Code>
+            <Message>Access Denied</Message>
+            <Resource>/mybucket/myfoto.jpg</Resource>
+            <RequestId>4442587FB7D0A2F9</RequestId>
+            <HostId>s9lzHYrFp76ZVxRcpX9+5cjAnEH2ROuNkd2BHfIa6UkFVdtjf5mKR3/eTPFvsiP/XV/VLi31234=</HostId>
+        </Error>
+        "#;
+
+        let parsed = S3ErrorResponse::parse(input).unwrap();
+        assert_eq!(parsed.code, "AccessDenied");
+        assert_eq!(parsed.message.as_deref(), Some("Access Denied"));
+        assert_eq!(parsed.resource.as_deref(), Some("/mybucket/myfoto.jpg"));
+        assert_eq!(parsed.request_id.as_deref(), Some("4442587FB7D0A2F9"));
+        assert_eq!(
+            parsed.host_id.as_deref(),
+            Some("s9lzHYrFp76ZVxRcpX9+5cjAnEH2ROuNkd2BHfIa6UkFVdtjf5mKR3/eTPFvsiP/XV/VLi31234=")
+        );
+        assert!(parsed.region.is_none());
+        assert!(parsed.endpoint.is_none());
+        assert_eq!(parsed.to_string(), "AccessDenied: Access Denied");
+    }
+
+    #[test]
+    fn parse_permanent_redirect() {
+        let input = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>PermanentRedirect</Code>
+            <Message>The bucket you are attempting to access must be addressed using the specified endpoint. Please send all future requests to this endpoint.</Message>
+            <Endpoint>rusty-s3.s3.eu-west-1.amazonaws.com</Endpoint>
+            <Bucket>rusty-s3</Bucket>
+            <RequestId>0NJFC4Y7P8RS9D7Z</RequestId>
+            <HostId>VbH3ARBO3rLr0O2wp9PkBnHXR3YFXFO8s0ROMqDf3xZfKD0WcQ3Z1a2ECxe2NYKNYUFfK1YZt5o=</HostId>
+        </Error>
+        "#;
+
+        let parsed = S3ErrorResponse::parse(input).unwrap();
+        assert_eq!(parsed.code, "PermanentRedirect");
+        assert_eq!(
+            parsed.endpoint.as_deref(),
+            Some("rusty-s3.s3.eu-west-1.amazonaws.com")
+        );
+        assert_eq!(parsed.request_id.as_deref(), Some("0NJFC4Y7P8RS9D7Z"));
+        assert!(parsed.resource.is_none());
+        assert_eq!(parsed.redirect_region(), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn redirect_region() {
+        let error = |region: Option<&str>, endpoint: Option<&str>| S3ErrorResponse {
+            code: "PermanentRedirect".to_owned(),
+            message: None,
+            request_id: None,
+            host_id: None,
+            resource: None,
+            region: region.map(str::to_owned),
+            endpoint: endpoint.map(str::to_owned),
+        };
+
+        assert_eq!(
+            error(
+                Some("ap-south-1"),
+                Some("rusty-s3.s3.eu-west-1.amazonaws.com")
+            )
+            .redirect_region(),
+            Some("ap-south-1")
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-eu-west-1.amazonaws.com")).redirect_region(),
+            Some("eu-west-1")
+        );
+        assert_eq!(
+            error(
+                None,
+                Some("rusty-s3.s3.dualstack.cn-north-1.amazonaws.com.cn")
+            )
+            .redirect_region(),
+            Some("cn-north-1")
+        );
+        // the global endpoint doesn't say which region to use
+        assert_eq!(
+            error(None, Some("rusty-s3.s3.amazonaws.com")).redirect_region(),
+            None
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3.us-gov-west-1.amazonaws.com")).redirect_region(),
+            Some("us-gov-west-1")
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-external-1.amazonaws.com")).redirect_region(),
+            Some("us-east-1")
+        );
+        // endpoints which aren't regions
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-accelerate.amazonaws.com")).redirect_region(),
+            None
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-accelerate.dualstack.amazonaws.com")).redirect_region(),
+            None
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-website-us-east-1.amazonaws.com")).redirect_region(),
+            None
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-website.eu-west-1.amazonaws.com")).redirect_region(),
+            None
+        );
+        assert_eq!(
+            error(None, Some("rusty-s3.s3-fips.amazonaws.com")).redirect_region(),
+            None
+        );
+        assert_eq!(error(None, Some("localhost:9000")).redirect_region(), None);
+        assert_eq!(error(None, None).redirect_region(), None);
+    }
+}
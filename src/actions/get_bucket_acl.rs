@@ -0,0 +1,320 @@
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the access control list of a bucket.
+///
+/// Find out more about `GetBucketAcl` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketAcl.html
+#[derive(Debug, Clone)]
+pub struct GetBucketAcl<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+/// The access control list of a bucket
+///
+/// Can be sent back to S3 by [`PutBucketAcl::body_with_md5`](crate::actions::PutBucketAcl::body_with_md5).
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetBucketAclResponse {
+    pub owner: AclOwner,
+    pub grants: Vec<Grant>,
+}
+
+/// The owner of a bucket
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AclOwner {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "DisplayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+/// A permission given to a [`Grantee`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grant {
+    pub grantee: Grantee,
+    pub permission: Permission,
+}
+
+/// Who a [`Grant`] applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Grantee {
+    /// An AWS account, identified by its canonical user id
+    CanonicalUser {
+        id: String,
+        display_name: Option<String>,
+    },
+    /// An AWS account, identified by its email address
+    Email(String),
+    /// A predefined group, identified by its uri,
+    /// like `http://acs.amazonaws.com/groups/global/AllUsers`
+    Group(String),
+}
+
+impl Grantee {
+    /// The `xsi:type` of this `Grantee`
+    pub(super) const fn type_str(&self) -> &'static str {
+        match self {
+            Self::CanonicalUser { .. } => "CanonicalUser",
+            Self::Email(_) => "AmazonCustomerByEmail",
+            Self::Group(_) => "Group",
+        }
+    }
+}
+
+/// The permission given by a [`Grant`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Permission {
+    FullControl,
+    Write,
+    WriteAcp,
+    Read,
+    ReadAcp,
+}
+
+#[derive(Deserialize)]
+struct AccessControlPolicy {
+    #[serde(rename = "Owner")]
+    owner: AclOwner,
+    #[serde(rename = "AccessControlList")]
+    access_control_list: AccessControlList,
+}
+
+#[derive(Deserialize)]
+struct AccessControlList {
+    #[serde(rename = "Grant", default)]
+    grants: Vec<GrantRepr>,
+}
+
+#[derive(Deserialize)]
+struct GrantRepr {
+    #[serde(rename = "Grantee")]
+    grantee: GranteeRepr,
+    #[serde(rename = "Permission")]
+    permission: Permission,
+}
+
+#[derive(Deserialize)]
+struct GranteeRepr {
+    #[serde(rename = "@type")]
+    type_: String,
+    #[serde(rename = "ID")]
+    id: Option<String>,
+    #[serde(rename = "DisplayName")]
+    display_name: Option<String>,
+    #[serde(rename = "EmailAddress")]
+    email_address: Option<String>,
+    #[serde(rename = "URI")]
+    uri: Option<String>,
+}
+
+impl GranteeRepr {
+    fn into_grantee(self) -> Result<Grantee, quick_xml::DeError> {
+        let missing = |field| quick_xml::DeError::Custom(format!("missing grantee `{field}`"));
+
+        match self.type_.as_str() {
+            "CanonicalUser" => Ok(Grantee::CanonicalUser {
+                id: self.id.ok_or_else(|| missing("ID"))?,
+                display_name: self.display_name,
+            }),
+            "AmazonCustomerByEmail" => self
+                .email_address
+                .map(Grantee::Email)
+                .ok_or_else(|| missing("EmailAddress")),
+            "Group" => self.uri.map(Grantee::Group).ok_or_else(|| missing("URI")),
+            type_ => Err(quick_xml::DeError::Custom(format!(
+                "unknown grantee type `{type_}`"
+            ))),
+        }
+    }
+}
+
+impl<'a> GetBucketAcl<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(s: impl AsRef<[u8]>) -> Result<GetBucketAclResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<GetBucketAclResponse, quick_xml::DeError> {
+        let policy: AccessControlPolicy = quick_xml::de::from_reader(BufReader::new(s))?;
+
+        let grants = policy
+            .access_control_list
+            .grants
+            .into_iter()
+            .map(|grant| {
+                Ok(Grant {
+                    grantee: grant.grantee.into_grantee()?,
+                    permission: grant.permission,
+                })
+            })
+            .collect::<Result<_, quick_xml::DeError>>()?;
+        Ok(GetBucketAclResponse {
+            owner: policy.owner,
+            grants,
+        })
+    }
+}
+
+impl<'a> S3Action<'a> for GetBucketAcl<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("acl", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = GetBucketAcl::new(&bucket, None);
+        action.query_mut().insert("foo", "bar");
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?acl=&foo=bar";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <AccessControlPolicy xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Owner>
+                <ID>75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a</ID>
+                <DisplayName>CustomersName@amazon.com</DisplayName>
+            </Owner>
+            <AccessControlList>
+                <Grant>
+                    <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="CanonicalUser">
+                        <ID>75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a</ID>
+                        <DisplayName>CustomersName@amazon.com</DisplayName>
+                    </Grantee>
+                    <Permission>FULL_CONTROL</Permission>
+                </Grant>
+                <Grant>
+                    <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Group">
+                        <URI>http://acs.amazonaws.com/groups/global/AllUsers</URI>
+                    </Grantee>
+                    <Permission>READ</Permission>
+                </Grant>
+            </AccessControlList>
+        </AccessControlPolicy>"#;
+
+        let parsed = GetBucketAcl::parse_response(input).unwrap();
+        assert_eq!(
+            parsed.owner,
+            AclOwner {
+                id: "75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a".to_owned(),
+                display_name: Some("CustomersName@amazon.com".to_owned()),
+            }
+        );
+        assert_eq!(
+            parsed.grants,
+            [
+                Grant {
+                    grantee: Grantee::CanonicalUser {
+                        id: "75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a"
+                            .to_owned(),
+                        display_name: Some("CustomersName@amazon.com".to_owned()),
+                    },
+                    permission: Permission::FullControl,
+                },
+                Grant {
+                    grantee: Grantee::Group(
+                        "http://acs.amazonaws.com/groups/global/AllUsers".to_owned()
+                    ),
+                    permission: Permission::Read,
+                },
+            ]
+        );
+    }
+}
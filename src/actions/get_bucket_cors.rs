@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the CORS configuration of a bucket.
+///
+/// Find out more about `GetBucketCors` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketCors.html
+#[derive(Debug, Clone)]
+pub struct GetBucketCors<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetBucketCorsResponse {
+    #[serde(rename = "CORSRule", default)]
+    pub rules: Vec<CorsRule>,
+}
+
+/// A rule allowing cross-origin requests to a bucket
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorsRule {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "AllowedHeader", default)]
+    pub allowed_headers: Vec<String>,
+    #[serde(rename = "AllowedMethod", default)]
+    pub allowed_methods: Vec<String>,
+    #[serde(rename = "AllowedOrigin", default)]
+    pub allowed_origins: Vec<String>,
+    #[serde(rename = "ExposeHeader", default)]
+    pub expose_headers: Vec<String>,
+    #[serde(rename = "MaxAgeSeconds", skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<u32>,
+}
+
+impl<'a> GetBucketCors<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(
+        s: impl AsRef<[u8]>,
+    ) -> Result<GetBucketCorsResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<GetBucketCorsResponse, quick_xml::DeError> {
+        quick_xml::de::from_reader(BufReader::new(s))
+    }
+}
+
+impl<'a> S3Action<'a> for GetBucketCors<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("cors", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let action = GetBucketCors::new(&bucket, None);
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?cors=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CORSConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <CORSRule>
+                <ID>uploads</ID>
+                <AllowedHeader>*</AllowedHeader>
+                <AllowedMethod>PUT</AllowedMethod>
+                <AllowedMethod>POST</AllowedMethod>
+                <AllowedOrigin>https://www.example.com</AllowedOrigin>
+                <ExposeHeader>ETag</ExposeHeader>
+                <ExposeHeader>x-amz-version-id</ExposeHeader>
+                <MaxAgeSeconds>3000</MaxAgeSeconds>
+            </CORSRule>
+            <CORSRule>
+                <AllowedMethod>GET</AllowedMethod>
+                <AllowedOrigin>*</AllowedOrigin>
+            </CORSRule>
+        </CORSConfiguration>"#;
+
+        let parsed = GetBucketCors::parse_response(input).unwrap();
+        assert_eq!(
+            parsed.rules,
+            vec![
+                CorsRule {
+                    id: Some("uploads".to_owned()),
+                    allowed_headers: vec!["*".to_owned()],
+                    allowed_methods: vec!["PUT".to_owned(), "POST".to_owned()],
+                    allowed_origins: vec!["https://www.example.com".to_owned()],
+                    expose_headers: vec!["ETag".to_owned(), "x-amz-version-id".to_owned()],
+                    max_age_seconds: Some(3000),
+                },
+                CorsRule {
+                    allowed_methods: vec!["GET".to_owned()],
+                    allowed_origins: vec!["*".to_owned()],
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the lifecycle rules of a bucket.
+///
+/// Find out more about `GetBucketLifecycleConfiguration` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketLifecycleConfiguration.html
+#[derive(Debug, Clone)]
+pub struct GetBucketLifecycleConfiguration<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+/// A lifecycle rule of a bucket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleRule {
+    pub id: Option<String>,
+    /// `Enabled` or `Disabled`
+    pub status: String,
+    /// The prefix the rule applies to, for rules not using a [`LifecycleFilter`]
+    pub prefix: Option<String>,
+    pub filter: Option<LifecycleFilter>,
+    pub expiration: Option<LifecycleTime>,
+    pub transitions: Vec<LifecycleTransition>,
+}
+
+/// The objects a [`LifecycleRule`] applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleFilter {
+    /// Objects whose key starts with the prefix
+    Prefix(String),
+    /// Objects having the tag
+    Tag(LifecycleTag),
+    /// Objects matching the prefix, if any, and all of the tags
+    And {
+        prefix: Option<String>,
+        tags: Vec<LifecycleTag>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LifecycleTag {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+/// When a lifecycle action happens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleTime {
+    /// The number of days after the creation of the object
+    Days(u32),
+    /// An ISO 8601 date
+    Date(String),
+}
+
+/// The move of objects to another storage class
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleTransition {
+    pub time: LifecycleTime,
+    pub storage_class: String,
+}
+
+#[derive(Deserialize)]
+struct LifecycleConfigurationSerde {
+    #[serde(rename = "Rule", default)]
+    rules: Vec<RuleSerde>,
+}
+
+#[derive(Deserialize)]
+struct RuleSerde {
+    #[serde(rename = "ID")]
+    id: Option<String>,
+    #[serde(rename = "Status")]
+    status: String,
+    #[serde(rename = "Prefix")]
+    prefix: Option<String>,
+    #[serde(rename = "Filter")]
+    filter: Option<FilterSerde>,
+    #[serde(rename = "Expiration")]
+    expiration: Option<TimeSerde>,
+    #[serde(rename = "Transition", default)]
+    transitions: Vec<TransitionSerde>,
+}
+
+#[derive(Deserialize)]
+struct FilterSerde {
+    #[serde(rename = "Prefix")]
+    prefix: Option<String>,
+    #[serde(rename = "Tag")]
+    tag: Option<LifecycleTag>,
+    #[serde(rename = "And")]
+    and: Option<AndSerde>,
+}
+
+#[derive(Deserialize)]
+struct AndSerde {
+    #[serde(rename = "Prefix")]
+    prefix: Option<String>,
+    #[serde(rename = "Tag", default)]
+    tags: Vec<LifecycleTag>,
+}
+
+#[derive(Deserialize)]
+struct TimeSerde {
+    #[serde(rename = "Days")]
+    days: Option<u32>,
+    #[serde(rename = "Date")]
+    date: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TransitionSerde {
+    #[serde(rename = "Days")]
+    days: Option<u32>,
+    #[serde(rename = "Date")]
+    date: Option<String>,
+    #[serde(rename = "StorageClass")]
+    storage_class: String,
+}
+
+impl FilterSerde {
+    fn into_filter(self) -> Option<LifecycleFilter> {
+        if let Some(and) = self.and {
+            return Some(LifecycleFilter::And {
+                prefix: and.prefix,
+                tags: and.tags,
+            });
+        }
+        if let Some(tag) = self.tag {
+            return Some(LifecycleFilter::Tag(tag));
+        }
+        self.prefix.map(LifecycleFilter::Prefix)
+    }
+}
+
+impl TimeSerde {
+    fn into_time(self) -> Option<LifecycleTime> {
+        self.days
+            .map(LifecycleTime::Days)
+            .or_else(|| self.date.map(LifecycleTime::Date))
+    }
+}
+
+impl<'a> GetBucketLifecycleConfiguration<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(s: impl AsRef<[u8]>) -> Result<Vec<LifecycleRule>, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<Vec<LifecycleRule>, quick_xml::DeError> {
+        let parsed: LifecycleConfigurationSerde = quick_xml::de::from_reader(BufReader::new(s))?;
+
+        let rules = parsed
+            .rules
+            .into_iter()
+            .map(|rule| LifecycleRule {
+                id: rule.id,
+                status: rule.status,
+                prefix: rule.prefix,
+                filter: rule.filter.and_then(FilterSerde::into_filter),
+                expiration: rule.expiration.and_then(TimeSerde::into_time),
+                transitions: rule
+                    .transitions
+                    .into_iter()
+                    .filter_map(|transition| {
+                        let time = TimeSerde {
+                            days: transition.days,
+                            date: transition.date,
+                        }
+                        .into_time()?;
+                        Some(LifecycleTransition {
+                            time,
+                            storage_class: transition.storage_class,
+                        })
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(rules)
+    }
+}
+
+impl<'a> S3Action<'a> for GetBucketLifecycleConfiguration<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("lifecycle", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    const CONFIGURATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+    <LifecycleConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+        <Rule>
+            <ID>archive logs</ID>
+            <Filter>
+                <Prefix>logs/</Prefix>
+            </Filter>
+            <Status>Enabled</Status>
+            <Transition>
+                <Days>30</Days>
+                <StorageClass>STANDARD_IA</StorageClass>
+            </Transition>
+            <Transition>
+                <Days>90</Days>
+                <StorageClass>GLACIER</StorageClass>
+            </Transition>
+            <Expiration>
+                <Days>365</Days>
+            </Expiration>
+        </Rule>
+        <Rule>
+            <ID>temporary</ID>
+            <Filter>
+                <Tag>
+                    <Key>temporary</Key>
+                    <Value>true</Value>
+                </Tag>
+            </Filter>
+            <Status>Disabled</Status>
+            <Expiration>
+                <Date>2023-01-01T00:00:00.000Z</Date>
+            </Expiration>
+        </Rule>
+        <Rule>
+            <ID>reports</ID>
+            <Filter>
+                <And>
+                    <Prefix>reports/</Prefix>
+                    <Tag>
+                        <Key>department</Key>
+                        <Value>finance</Value>
+                    </Tag>
+                    <Tag>
+                        <Key>retention</Key>
+                        <Value>long</Value>
+                    </Tag>
+                </And>
+            </Filter>
+            <Status>Enabled</Status>
+            <Transition>
+                <Date>2024-06-01T00:00:00.000Z</Date>
+                <StorageClass>DEEP_ARCHIVE</StorageClass>
+            </Transition>
+        </Rule>
+        <Rule>
+            <ID>legacy</ID>
+            <Prefix>old/</Prefix>
+            <Status>Enabled</Status>
+            <Expiration>
+                <Days>7</Days>
+            </Expiration>
+        </Rule>
+    </LifecycleConfiguration>"#;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let action = GetBucketLifecycleConfiguration::new(&bucket, None);
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?lifecycle=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        let rules = GetBucketLifecycleConfiguration::parse_response(CONFIGURATION).unwrap();
+        assert_eq!(
+            rules,
+            vec![
+                LifecycleRule {
+                    id: Some("archive logs".to_owned()),
+                    status: "Enabled".to_owned(),
+                    prefix: None,
+                    filter: Some(LifecycleFilter::Prefix("logs/".to_owned())),
+                    expiration: Some(LifecycleTime::Days(365)),
+                    transitions: vec![
+                        LifecycleTransition {
+                            time: LifecycleTime::Days(30),
+                            storage_class: "STANDARD_IA".to_owned(),
+                        },
+                        LifecycleTransition {
+                            time: LifecycleTime::Days(90),
+                            storage_class: "GLACIER".to_owned(),
+                        },
+                    ],
+                },
+                LifecycleRule {
+                    id: Some("temporary".to_owned()),
+                    status: "Disabled".to_owned(),
+                    prefix: None,
+                    filter: Some(LifecycleFilter::Tag(LifecycleTag {
+                        key: "temporary".to_owned(),
+                        value: "true".to_owned(),
+                    })),
+                    expiration: Some(LifecycleTime::Date("2023-01-01T00:00:00.000Z".to_owned())),
+                    transitions: Vec::new(),
+                },
+                LifecycleRule {
+                    id: Some("reports".to_owned()),
+                    status: "Enabled".to_owned(),
+                    prefix: None,
+                    filter: Some(LifecycleFilter::And {
+                        prefix: Some("reports/".to_owned()),
+                        tags: vec![
+                            LifecycleTag {
+                                key: "department".to_owned(),
+                                value: "finance".to_owned(),
+                            },
+                            LifecycleTag {
+                                key: "retention".to_owned(),
+                                value: "long".to_owned(),
+                            },
+                        ],
+                    }),
+                    expiration: None,
+                    transitions: vec![LifecycleTransition {
+                        time: LifecycleTime::Date("2024-06-01T00:00:00.000Z".to_owned()),
+                        storage_class: "DEEP_ARCHIVE".to_owned(),
+                    }],
+                },
+                LifecycleRule {
+                    id: Some("legacy".to_owned()),
+                    status: "Enabled".to_owned(),
+                    prefix: Some("old/".to_owned()),
+                    filter: None,
+                    expiration: Some(LifecycleTime::Days(7)),
+                    transitions: Vec::new(),
+                },
+            ]
+        );
+    }
+}
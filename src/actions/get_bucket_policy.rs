@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::iter;
 use std::time::Duration;
 
@@ -7,6 +8,7 @@ use url::Url;
 
 use super::S3Action;
 use crate::actions::Method;
+use crate::credentials::IntoCredentials;
 use crate::signing::sign;
 use crate::sorting_iter::SortingIterator;
 use crate::{Bucket, Credentials, Map};
@@ -21,7 +23,7 @@ const POLICY_PARAM: &str = "policy";
 #[derive(Debug, Clone)]
 pub struct GetBucketPolicy<'a> {
     bucket: &'a Bucket,
-    credentials: Option<&'a Credentials>,
+    credentials: Option<Cow<'a, Credentials>>,
 
     query: Map<'a>,
     headers: Map<'a>,
@@ -39,10 +41,10 @@ pub struct GetBucketPolicyResponse {
 impl<'a> GetBucketPolicy<'a> {
     #[inline]
     #[must_use]
-    pub const fn new(bucket: &'a Bucket, credentials: Option<&'a Credentials>) -> Self {
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
         Self {
             bucket,
-            credentials,
+            credentials: credentials.into_credentials(),
 
             query: Map::new(),
             headers: Map::new(),
@@ -70,11 +72,15 @@ impl<'a> S3Action<'a> for GetBucketPolicy<'a> {
         &mut self.headers
     }
 
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
     fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
         let url = self.bucket.base_url().clone();
         let query = SortingIterator::new(iter::once((POLICY_PARAM, "")), self.query.iter());
 
-        match self.credentials {
+        match self.credentials.as_deref() {
             Some(credentials) => sign(
                 time,
                 Self::METHOD,
@@ -83,6 +89,7 @@ impl<'a> S3Action<'a> for GetBucketPolicy<'a> {
                 credentials.secret(),
                 credentials.token(),
                 self.bucket.region(),
+                self.bucket.service(),
                 expires_in.as_secs(),
                 query,
                 self.headers.iter(),
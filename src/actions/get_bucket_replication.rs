@@ -0,0 +1,277 @@
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the replication configuration of a bucket.
+///
+/// Find out more about `GetBucketReplication` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketReplication.html
+#[derive(Debug, Clone)]
+pub struct GetBucketReplication<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetBucketReplicationResponse {
+    /// The IAM role S3 assumes to replicate the objects
+    #[serde(rename = "Role")]
+    pub role: String,
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<ReplicationRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReplicationRule {
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    #[serde(rename = "Priority")]
+    pub priority: Option<u32>,
+    /// `Enabled` or `Disabled`
+    #[serde(rename = "Status")]
+    pub status: String,
+    #[serde(rename = "Destination")]
+    pub destination: ReplicationDestination,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReplicationDestination {
+    /// The ARN of the destination bucket
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    #[serde(rename = "Account")]
+    pub account: Option<String>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<String>,
+    #[serde(rename = "Metrics")]
+    pub metrics: Option<ReplicationMetrics>,
+    /// The S3 Replication Time Control (RTC) settings
+    #[serde(rename = "ReplicationTime")]
+    pub replication_time: Option<ReplicationTime>,
+}
+
+/// Whether replication metrics and events are enabled
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReplicationMetrics {
+    /// `Enabled` or `Disabled`
+    #[serde(rename = "Status")]
+    pub status: String,
+    /// The time after which a `OperationMissedThreshold` event is emitted
+    #[serde(rename = "EventThreshold")]
+    pub event_threshold: Option<ReplicationTimeValue>,
+}
+
+/// The time within which S3 must replicate objects
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReplicationTime {
+    /// `Enabled` or `Disabled`
+    #[serde(rename = "Status")]
+    pub status: String,
+    #[serde(rename = "Time")]
+    pub time: ReplicationTimeValue,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReplicationTimeValue {
+    #[serde(rename = "Minutes")]
+    pub minutes: u32,
+}
+
+impl<'a> GetBucketReplication<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(
+        s: impl AsRef<[u8]>,
+    ) -> Result<GetBucketReplicationResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<GetBucketReplicationResponse, quick_xml::DeError> {
+        quick_xml::de::from_reader(BufReader::new(s))
+    }
+}
+
+impl<'a> S3Action<'a> for GetBucketReplication<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("replication", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let action = GetBucketReplication::new(&bucket, None);
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?replication=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse_replication_time_control() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ReplicationConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Role>arn:aws:iam::123456789012:role/replication</Role>
+            <Rule>
+                <ID>rtc</ID>
+                <Priority>1</Priority>
+                <Status>Enabled</Status>
+                <Filter>
+                    <Prefix>documents/</Prefix>
+                </Filter>
+                <DeleteMarkerReplication>
+                    <Status>Disabled</Status>
+                </DeleteMarkerReplication>
+                <Destination>
+                    <Bucket>arn:aws:s3:::destinationbucket</Bucket>
+                    <StorageClass>STANDARD_IA</StorageClass>
+                    <Metrics>
+                        <Status>Enabled</Status>
+                        <EventThreshold>
+                            <Minutes>15</Minutes>
+                        </EventThreshold>
+                    </Metrics>
+                    <ReplicationTime>
+                        <Status>Enabled</Status>
+                        <Time>
+                            <Minutes>15</Minutes>
+                        </Time>
+                    </ReplicationTime>
+                </Destination>
+            </Rule>
+            <Rule>
+                <ID>plain</ID>
+                <Status>Disabled</Status>
+                <Destination>
+                    <Bucket>arn:aws:s3:::otherbucket</Bucket>
+                </Destination>
+            </Rule>
+        </ReplicationConfiguration>"#;
+
+        let parsed = GetBucketReplication::parse_response(input).unwrap();
+        assert_eq!(parsed.role, "arn:aws:iam::123456789012:role/replication");
+        assert_eq!(
+            parsed.rules,
+            vec![
+                ReplicationRule {
+                    id: Some("rtc".to_owned()),
+                    priority: Some(1),
+                    status: "Enabled".to_owned(),
+                    destination: ReplicationDestination {
+                        bucket: "arn:aws:s3:::destinationbucket".to_owned(),
+                        account: None,
+                        storage_class: Some("STANDARD_IA".to_owned()),
+                        metrics: Some(ReplicationMetrics {
+                            status: "Enabled".to_owned(),
+                            event_threshold: Some(ReplicationTimeValue { minutes: 15 }),
+                        }),
+                        replication_time: Some(ReplicationTime {
+                            status: "Enabled".to_owned(),
+                            time: ReplicationTimeValue { minutes: 15 },
+                        }),
+                    },
+                },
+                ReplicationRule {
+                    id: Some("plain".to_owned()),
+                    priority: None,
+                    status: "Disabled".to_owned(),
+                    destination: ReplicationDestination {
+                        bucket: "arn:aws:s3:::otherbucket".to_owned(),
+                        account: None,
+                        storage_class: None,
+                        metrics: None,
+                        replication_time: None,
+                    },
+                },
+            ]
+        );
+    }
+}
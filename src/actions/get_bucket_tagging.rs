@@ -0,0 +1,233 @@
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::actions::S3ErrorResponse;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the tags of a bucket.
+///
+/// Find out more about `GetBucketTagging` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketTagging.html
+#[derive(Debug, Clone)]
+pub struct GetBucketTagging<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GetBucketTaggingResponse {
+    #[serde(rename = "TagSet")]
+    tag_set: TagSet,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct TagSet {
+    #[serde(rename = "Tag", default)]
+    tags: Vec<Tag>,
+}
+
+impl GetBucketTaggingResponse {
+    /// The tags of the bucket
+    #[must_use]
+    pub fn tags(&self) -> &[Tag] {
+        &self.tag_set.tags
+    }
+
+    /// Take the tags of the bucket
+    #[must_use]
+    pub fn into_tags(self) -> Vec<Tag> {
+        self.tag_set.tags
+    }
+}
+
+/// A tag of a bucket
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+impl<'a> GetBucketTagging<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// S3 responds with a `NoSuchTagSet` error if the bucket has no tags,
+    /// which is parsed as an empty tag set.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(
+        s: impl AsRef<[u8]>,
+    ) -> Result<GetBucketTaggingResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// S3 responds with a `NoSuchTagSet` error if the bucket has no tags,
+    /// which is parsed as an empty tag set.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        mut s: impl Read,
+    ) -> Result<GetBucketTaggingResponse, quick_xml::DeError> {
+        let mut body = Vec::new();
+        s.read_to_end(&mut body).map_err(quick_xml::Error::from)?;
+        if S3ErrorResponse::parse(&body).is_ok_and(|error| error.code == "NoSuchTagSet") {
+            return Ok(GetBucketTaggingResponse::default());
+        }
+
+        quick_xml::de::from_reader(BufReader::new(&*body))
+    }
+}
+
+impl<'a> S3Action<'a> for GetBucketTagging<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("tagging", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let action = GetBucketTagging::new(&bucket, None);
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?tagging=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <TagSet>
+                <Tag>
+                    <Key>cost-center</Key>
+                    <Value>1234</Value>
+                </Tag>
+                <Tag>
+                    <Key>team</Key>
+                    <Value>storage</Value>
+                </Tag>
+            </TagSet>
+        </Tagging>"#;
+
+        let parsed = GetBucketTagging::parse_response(input).unwrap();
+        assert_eq!(
+            parsed.tags(),
+            [
+                Tag {
+                    key: "cost-center".to_owned(),
+                    value: "1234".to_owned(),
+                },
+                Tag {
+                    key: "team".to_owned(),
+                    value: "storage".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_no_such_tag_set() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>NoSuchTagSet</Code>
+            <Message>The TagSet does not exist</Message>
+            <BucketName>examplebucket</BucketName>
+            <RequestId>4442587FB7D0A2F9</RequestId>
+        </Error>"#;
+
+        let parsed = GetBucketTagging::parse_response(input).unwrap();
+        assert!(parsed.tags().is_empty());
+
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>AccessDenied</Code>
+            <Message>Access Denied</Message>
+        </Error>"#;
+        assert!(GetBucketTagging::parse_response(input).is_err());
+    }
+}
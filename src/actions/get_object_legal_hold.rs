@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the object lock legal hold status of an object.
+///
+/// Find out more about `GetObjectLegalHold` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectLegalHold.html
+#[derive(Debug, Clone)]
+pub struct GetObjectLegalHold<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    object: &'a str,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+/// Whether an object is under a legal hold
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub enum LegalHoldStatus {
+    #[serde(rename = "ON")]
+    On,
+    #[serde(rename = "OFF")]
+    Off,
+}
+
+impl LegalHoldStatus {
+    /// Convert this `LegalHoldStatus` into the uppercase name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::LegalHoldStatus;
+    /// assert_eq!(LegalHoldStatus::On.to_str(), "ON");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::On => "ON",
+            Self::Off => "OFF",
+        }
+    }
+}
+
+impl Display for LegalHoldStatus {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub struct GetObjectLegalHoldResponse {
+    #[serde(rename = "Status")]
+    pub status: LegalHoldStatus,
+}
+
+impl<'a> GetObjectLegalHold<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>, object: &'a str) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            object,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Retrieve the legal hold of the version `version_id` of the object
+    pub fn with_version_id(&mut self, version_id: impl Into<Cow<'a, str>>) {
+        self.query.insert("versionId", version_id);
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(
+        s: impl AsRef<[u8]>,
+    ) -> Result<GetObjectLegalHoldResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<GetObjectLegalHoldResponse, quick_xml::DeError> {
+        quick_xml::de::from_reader(BufReader::new(s))
+    }
+}
+
+impl<'a> S3Action<'a> for GetObjectLegalHold<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.object_url(self.object).unwrap();
+        let query = SortingIterator::new(iter::once(("legal-hold", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let action = GetObjectLegalHold::new(&bucket, None, "report.pdf");
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/report.pdf?legal-hold=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <LegalHold xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Status>ON</Status>
+        </LegalHold>"#;
+
+        let parsed = GetObjectLegalHold::parse_response(input).unwrap();
+        assert_eq!(parsed.status, LegalHoldStatus::On);
+    }
+}
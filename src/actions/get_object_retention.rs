@@ -0,0 +1,204 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Retrieve the object lock retention of an object.
+///
+/// Find out more about `GetObjectRetention` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectRetention.html
+#[derive(Debug, Clone)]
+pub struct GetObjectRetention<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    object: &'a str,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+/// The object lock retention mode of an object
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub enum RetentionMode {
+    /// Users with the `s3:BypassGovernanceRetention` permission
+    /// can still alter the retention or delete the object
+    #[serde(rename = "GOVERNANCE")]
+    Governance,
+    /// Nobody can alter the retention or delete the object
+    #[serde(rename = "COMPLIANCE")]
+    Compliance,
+}
+
+impl RetentionMode {
+    /// Convert this `RetentionMode` into the uppercase name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::RetentionMode;
+    /// assert_eq!(RetentionMode::Governance.to_str(), "GOVERNANCE");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Governance => "GOVERNANCE",
+            Self::Compliance => "COMPLIANCE",
+        }
+    }
+}
+
+impl Display for RetentionMode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GetObjectRetentionResponse {
+    #[serde(rename = "Mode")]
+    pub mode: Option<RetentionMode>,
+    #[serde(rename = "RetainUntilDate")]
+    pub retain_until_date: Option<String>,
+}
+
+impl<'a> GetObjectRetention<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>, object: &'a str) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            object,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Retrieve the retention of the version `version_id` of the object
+    pub fn with_version_id(&mut self, version_id: impl Into<Cow<'a, str>>) {
+        self.query.insert("versionId", version_id);
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response(
+        s: impl AsRef<[u8]>,
+    ) -> Result<GetObjectRetentionResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the body is not valid XML
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<GetObjectRetentionResponse, quick_xml::DeError> {
+        quick_xml::de::from_reader(BufReader::new(s))
+    }
+}
+
+impl<'a> S3Action<'a> for GetObjectRetention<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.object_url(self.object).unwrap();
+        let query = SortingIterator::new(iter::once(("retention", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = GetObjectRetention::new(&bucket, None, "report.pdf");
+        action.with_version_id("abcd");
+        let url = action.sign(expires_in);
+        let expected =
+            "https://examplebucket.s3.amazonaws.com/report.pdf?retention=&versionId=abcd";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Retention xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Mode>GOVERNANCE</Mode>
+            <RetainUntilDate>2030-01-01T00:00:00.000Z</RetainUntilDate>
+        </Retention>"#;
+
+        let parsed = GetObjectRetention::parse_response(input).unwrap();
+        assert_eq!(
+            parsed,
+            GetObjectRetentionResponse {
+                mode: Some(RetentionMode::Governance),
+                retain_until_date: Some("2030-01-01T00:00:00.000Z".to_owned()),
+            }
+        );
+    }
+}
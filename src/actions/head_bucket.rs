@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::time::Duration;
 
 use time::OffsetDateTime;
@@ -5,6 +6,7 @@ use url::Url;
 
 use super::S3Action;
 use crate::actions::Method;
+use crate::credentials::IntoCredentials;
 use crate::signing::sign;
 use crate::{Bucket, Credentials, Map};
 
@@ -16,7 +18,7 @@ use crate::{Bucket, Credentials, Map};
 #[derive(Debug, Clone)]
 pub struct HeadBucket<'a> {
     bucket: &'a Bucket,
-    credentials: Option<&'a Credentials>,
+    credentials: Option<Cow<'a, Credentials>>,
 
     query: Map<'a>,
     headers: Map<'a>,
@@ -25,10 +27,10 @@ pub struct HeadBucket<'a> {
 impl<'a> HeadBucket<'a> {
     #[inline]
     #[must_use]
-    pub const fn new(bucket: &'a Bucket, credentials: Option<&'a Credentials>) -> Self {
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
         Self {
             bucket,
-            credentials,
+            credentials: credentials.into_credentials(),
 
             query: Map::new(),
             headers: Map::new(),
@@ -47,10 +49,14 @@ impl<'a> S3Action<'a> for HeadBucket<'a> {
         &mut self.headers
     }
 
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
     fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
         let url = self.bucket.base_url().clone();
 
-        match self.credentials {
+        match self.credentials.as_deref() {
             Some(credentials) => sign(
                 time,
                 Self::METHOD,
@@ -59,6 +65,7 @@ impl<'a> S3Action<'a> for HeadBucket<'a> {
                 credentials.secret(),
                 credentials.token(),
                 self.bucket.region(),
+                self.bucket.service(),
                 expires_in.as_secs(),
                 self.query.iter(),
                 self.headers.iter(),
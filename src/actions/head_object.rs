@@ -1,10 +1,14 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use time::OffsetDateTime;
 use url::Url;
 
+use super::object_read::impl_object_read_options;
 use super::S3Action;
-use crate::actions::Method;
+use crate::actions::{find_header, version_id_from_headers, Method};
+use crate::credentials::IntoCredentials;
 use crate::signing::sign;
 use crate::{Bucket, Credentials, Map};
 
@@ -16,32 +20,81 @@ use crate::{Bucket, Credentials, Map};
 #[derive(Debug, Clone)]
 pub struct HeadObject<'a> {
     bucket: &'a Bucket,
-    credentials: Option<&'a Credentials>,
+    credentials: Option<Cow<'a, Credentials>>,
     object: &'a str,
 
     query: Map<'a>,
     headers: Map<'a>,
 }
 
+/// The metadata of an object, as returned in the headers of a `HeadObject` response
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadObjectResponse {
+    pub content_length: Option<u64>,
+    pub content_type: Option<String>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub version_id: Option<String>,
+    /// The user-defined `x-amz-meta-*` metadata, keyed by lowercase name
+    /// without the `x-amz-meta-` prefix
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl HeadObjectResponse {
+    /// Parse the response from its headers
+    ///
+    /// Header names are matched case-insensitively, see [`find_header`].
+    /// Unknown headers are ignored.
+    pub fn from_headers<'h, I>(headers: I) -> Self
+    where
+        I: IntoIterator<Item = (&'h str, &'h str)>,
+    {
+        let headers = headers.into_iter().collect::<Vec<_>>();
+        let find = |name| find_header(headers.iter().copied(), name);
+
+        let metadata = headers
+            .iter()
+            .filter_map(|&(name, value)| {
+                let name = name.to_ascii_lowercase();
+                let key = name.strip_prefix("x-amz-meta-")?;
+                Some((key.to_owned(), value.to_owned()))
+            })
+            .collect();
+        Self {
+            content_length: find("content-length").and_then(|value| value.parse().ok()),
+            content_type: find("content-type").map(str::to_owned),
+            etag: find("etag").map(str::to_owned),
+            last_modified: find("last-modified").map(str::to_owned),
+            version_id: version_id_from_headers(headers.iter().copied()),
+            metadata,
+        }
+    }
+}
+
 impl<'a> HeadObject<'a> {
     #[inline]
     #[must_use]
-    pub const fn new(
-        bucket: &'a Bucket,
-        credentials: Option<&'a Credentials>,
-        object: &'a str,
-    ) -> Self {
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>, object: &'a str) -> Self {
         Self {
             bucket,
-            credentials,
+            credentials: credentials.into_credentials(),
             object,
 
             query: Map::new(),
             headers: Map::new(),
         }
     }
+
+    /// Retrieve the metadata of the version `version_id` of the object,
+    /// by setting the `versionId` query parameter.
+    pub fn with_version_id(&mut self, version_id: impl Into<Cow<'a, str>>) {
+        self.query.insert("versionId", version_id);
+    }
 }
 
+impl_object_read_options!(HeadObject);
+
 impl<'a> S3Action<'a> for HeadObject<'a> {
     const METHOD: Method = Method::Head;
 
@@ -53,10 +106,14 @@ impl<'a> S3Action<'a> for HeadObject<'a> {
         &mut self.headers
     }
 
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
     fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
         let url = self.bucket.object_url(self.object).unwrap();
 
-        match self.credentials {
+        match self.credentials.as_deref() {
             Some(credentials) => sign(
                 time,
                 Self::METHOD,
@@ -65,6 +122,7 @@ impl<'a> S3Action<'a> for HeadObject<'a> {
                 credentials.secret(),
                 credentials.token(),
                 self.bucket.region(),
+                self.bucket.service(),
                 expires_in.as_secs(),
                 self.query.iter(),
                 self.headers.iter(),
@@ -164,4 +222,71 @@ mod tests {
 
         assert_eq!(expected, url.as_str());
     }
+
+    #[test]
+    fn version_id() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = HeadObject::new(&bucket, None, "test.txt");
+        action.with_version_id("3/L4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY");
+        action.with_response_content_type("text/plain");
+
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/test.txt?response-content-type=text%2Fplain&versionId=3%2FL4kqtJlcpXroDTDmJ%2BrmSpXd3dIbrHY";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn parse_headers() {
+        let headers = [
+            ("Content-Length", "4274"),
+            ("Content-Type", "image/jpeg"),
+            ("ETag", "\"bfd537a51d15208163231b0711e0b1f3\""),
+            ("Last-Modified", "Tue, 01 Dec 2020 20:43:11 GMT"),
+            ("x-amz-meta-Animal", "duck"),
+            ("x-amz-meta-color", "yellow"),
+            ("x-amz-request-id", "4442587FB7D0A2F9"),
+        ];
+
+        let parsed = HeadObjectResponse::from_headers(headers);
+        assert_eq!(parsed.content_length, Some(4274));
+        assert_eq!(parsed.content_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(
+            parsed.etag.as_deref(),
+            Some("\"bfd537a51d15208163231b0711e0b1f3\"")
+        );
+        assert_eq!(
+            parsed.last_modified.as_deref(),
+            Some("Tue, 01 Dec 2020 20:43:11 GMT")
+        );
+        assert!(parsed.version_id.is_none());
+        assert_eq!(parsed.metadata.len(), 2);
+        assert_eq!(parsed.metadata["animal"], "duck");
+        assert_eq!(parsed.metadata["color"], "yellow");
+    }
+
+    #[test]
+    fn suggested_timeout() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let action = HeadObject::new(&bucket, None, "test.txt");
+        assert_eq!(action.suggested_timeout(), Some(Duration::from_secs(10)));
+    }
 }
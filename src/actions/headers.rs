@@ -0,0 +1,109 @@
+/// Find the value of the header `name` in the headers of a response
+///
+/// Header names are matched ASCII case-insensitively, since servers
+/// don't agree on the casing of names like `ETag`.
+/// If the header is repeated the first value is returned.
+///
+/// ```rust
+/// # use rusty_s3::actions::find_header;
+/// let headers = [("Content-Type", "image/jpeg"), ("ETag", "\"abcd\"")];
+/// assert_eq!(find_header(headers, "etag"), Some("\"abcd\""));
+/// assert_eq!(find_header(headers, "x-amz-version-id"), None);
+/// ```
+pub fn find_header<'h, I>(headers: I, name: &str) -> Option<&'h str>
+where
+    I: IntoIterator<Item = (&'h str, &'h str)>,
+{
+    headers
+        .into_iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value)
+}
+
+/// Get the version of the object created or deleted by a request,
+/// from the `x-amz-version-id` header of its response
+///
+/// The header is only returned for buckets which have versioning enabled
+/// or suspended. In the latter case its value is `null`.
+///
+/// ```rust
+/// # use rusty_s3::actions::version_id_from_headers;
+/// let headers = [("ETag", "\"abcd\""), ("x-amz-version-id", "3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY")];
+/// assert_eq!(
+///     version_id_from_headers(headers).as_deref(),
+///     Some("3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY")
+/// );
+/// ```
+pub fn version_id_from_headers<'h, I>(headers: I) -> Option<String>
+where
+    I: IntoIterator<Item = (&'h str, &'h str)>,
+{
+    find_header(headers, "x-amz-version-id").map(str::to_owned)
+}
+
+/// Get the region of the bucket from the `x-amz-bucket-region` header of a response
+///
+/// S3 returns it when redirecting requests sent to the wrong region,
+/// and in the response of [`HeadBucket`](crate::actions::HeadBucket).
+/// Pass it to [`Bucket::with_region`](crate::Bucket::with_region)
+/// in order to retry the request.
+///
+/// ```rust
+/// # use rusty_s3::actions::bucket_region_from_headers;
+/// let headers = [("x-amz-bucket-region", "eu-west-1")];
+/// assert_eq!(bucket_region_from_headers(headers), Some("eu-west-1"));
+/// ```
+pub fn bucket_region_from_headers<'h, I>(headers: I) -> Option<&'h str>
+where
+    I: IntoIterator<Item = (&'h str, &'h str)>,
+{
+    find_header(headers, "x-amz-bucket-region")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn mixed_case() {
+        let headers = [
+            ("Content-Length", "1234"),
+            ("etag", "\"first\""),
+            ("ETag", "\"second\""),
+            ("X-Amz-Version-Id", "abcd"),
+        ];
+
+        assert_eq!(find_header(headers, "content-length"), Some("1234"));
+        assert_eq!(find_header(headers, "CONTENT-LENGTH"), Some("1234"));
+        assert_eq!(find_header(headers, "ETag"), Some("\"first\""));
+        assert_eq!(find_header(headers, "x-amz-version-id"), Some("abcd"));
+        assert_eq!(find_header(headers, "x-amz-version"), None);
+        assert_eq!(find_header([], "etag"), None);
+    }
+
+    #[test]
+    fn version_id() {
+        let headers = [
+            (
+                "x-amz-id-2",
+                "Vvag1LuByRx9e6j5Onimru9pO4ZVKnJ2Qz7/C1NPcfTWAtRPfTaOFg==",
+            ),
+            ("x-amz-request-id", "4442587FB7D0A2F9"),
+            ("Date", "Wed, 12 Oct 2009 17:50:00 GMT"),
+            ("ETag", "\"1b2cf535f27731c974343645a3985328\""),
+            (
+                "x-amz-version-id",
+                "43jfkodU8493jnFJD9fjj3HHNVfdsQUIFDNsidf038jfdsjGFDSIRp",
+            ),
+            ("Content-Length", "0"),
+            ("Server", "AmazonS3"),
+        ];
+        assert_eq!(
+            version_id_from_headers(headers).as_deref(),
+            Some("43jfkodU8493jnFJD9fjj3HHNVfdsQUIFDNsidf038jfdsjGFDSIRp")
+        );
+        assert_eq!(version_id_from_headers(headers[..4].iter().copied()), None);
+    }
+}
@@ -0,0 +1,351 @@
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::iter;
+use std::time::Duration;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::list_objects_v2::{url_decode, CommonPrefixes};
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// List the versions of the objects in the bucket.
+///
+/// If `next_key_marker` is `Some` the response is truncated, and the rest
+/// of the list can be retrieved by reusing the `ListObjectVersions` action
+/// but with `key-marker` and `version-id-marker` set to the `next_key_marker`
+/// and `next_version_id_marker` received in the previous response,
+/// for example using [`ListObjectVersions::next_page`].
+///
+/// The listing is requested with `encoding-type=url`, so that keys which
+/// can't be represented in XML are returned correctly. Keys, prefixes and
+/// markers are decoded while parsing the response.
+///
+/// Find out more about `ListObjectVersions` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListObjectVersions.html
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct ListObjectVersions<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListObjectVersionsResponse {
+    #[serde(rename = "Version", default)]
+    pub versions: Vec<ObjectVersion>,
+    #[serde(rename = "DeleteMarker", default)]
+    pub delete_markers: Vec<ObjectDeleteMarker>,
+    #[serde(rename = "CommonPrefixes", default)]
+    pub common_prefixes: Vec<CommonPrefixes>,
+    #[serde(rename = "MaxKeys")]
+    pub max_keys: Option<u16>,
+    #[serde(rename = "IsTruncated")]
+    is_truncated: bool,
+    #[serde(rename = "NextKeyMarker")]
+    pub next_key_marker: Option<String>,
+    #[serde(rename = "NextVersionIdMarker")]
+    pub next_version_id_marker: Option<String>,
+    #[serde(rename = "EncodingType")]
+    encoding_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectVersion {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+    #[serde(rename = "IsLatest")]
+    pub is_latest: bool,
+    #[serde(rename = "LastModified")]
+    pub last_modified: String,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "Size")]
+    pub size: u64,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectDeleteMarker {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+    #[serde(rename = "IsLatest")]
+    pub is_latest: bool,
+    #[serde(rename = "LastModified")]
+    pub last_modified: String,
+}
+
+impl<'a> ListObjectVersions<'a> {
+    #[must_use]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>) -> Self {
+        let mut query = Map::new();
+        query.insert("encoding-type", "url");
+
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+
+            query,
+            headers: Map::new(),
+        }
+    }
+
+    /// Limits the response to keys that begin with the specified prefix.
+    pub fn with_prefix(&mut self, prefix: impl Into<Cow<'a, str>>) {
+        self.query.insert("prefix", prefix);
+    }
+
+    /// A delimiter is a character that you use to group keys.
+    pub fn with_delimiter(&mut self, delimiter: impl Into<Cow<'a, str>>) {
+        self.query.insert("delimiter", delimiter);
+    }
+
+    /// Start listing after this key.
+    ///
+    /// The key must not be url-encoded, it's encoded while signing the request.
+    pub fn with_key_marker(&mut self, key_marker: impl Into<Cow<'a, str>>) {
+        self.query.insert("key-marker", key_marker);
+    }
+
+    /// Start listing after this version of the key set by
+    /// [`ListObjectVersions::with_key_marker`].
+    pub fn with_version_id_marker(&mut self, version_id_marker: impl Into<Cow<'a, str>>) {
+        self.query.insert("version-id-marker", version_id_marker);
+    }
+
+    /// Sets the maximum number of keys returned in the response.
+    /// By default, the action returns up to 1,000 key names.
+    pub fn with_max_keys(&mut self, max_keys: usize) {
+        self.query.insert("max-keys", max_keys.to_string());
+    }
+
+    /// Clone this action, setting the `key-marker` and the `version-id-marker`
+    ///
+    /// `key_marker` and `version_id_marker` should be the `next_key_marker`
+    /// and the `next_version_id_marker` of the previous page, as parsed by
+    /// [`ListObjectVersions::parse_response`].
+    #[must_use]
+    pub fn next_page(&self, key_marker: &str, version_id_marker: Option<&str>) -> Self {
+        let mut action = self.clone();
+        action.with_key_marker(key_marker.to_owned());
+        match version_id_marker {
+            Some(version_id_marker) => {
+                action.with_version_id_marker(version_id_marker.to_owned());
+            }
+            None => {
+                action.query.remove("version-id-marker");
+            }
+        }
+        action
+    }
+
+    /// Parse the XML response from S3 into a struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the XML response could not be parsed.
+    pub fn parse_response(
+        s: impl AsRef<[u8]>,
+    ) -> Result<ListObjectVersionsResponse, quick_xml::DeError> {
+        Self::parse_response_from_reader(&mut s.as_ref())
+    }
+
+    /// Parse the XML response from S3 into a struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the XML response could not be parsed.
+    pub fn parse_response_from_reader(
+        s: impl Read,
+    ) -> Result<ListObjectVersionsResponse, quick_xml::DeError> {
+        let mut parsed: ListObjectVersionsResponse = quick_xml::de::from_reader(BufReader::new(s))?;
+
+        if !parsed.is_truncated {
+            parsed.next_key_marker = None;
+            parsed.next_version_id_marker = None;
+        }
+
+        if parsed.encoding_type.as_deref() == Some("url") {
+            for version in &mut parsed.versions {
+                url_decode(&mut version.key);
+            }
+            for delete_marker in &mut parsed.delete_markers {
+                url_decode(&mut delete_marker.key);
+            }
+            for common_prefix in &mut parsed.common_prefixes {
+                url_decode(&mut common_prefix.prefix);
+            }
+            if let Some(next_key_marker) = &mut parsed.next_key_marker {
+                url_decode(next_key_marker);
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+impl<'a> S3Action<'a> for ListObjectVersions<'a> {
+    const METHOD: Method = Method::Get;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("versions", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    fn bucket() -> Bucket {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let bucket = bucket();
+        let mut action = ListObjectVersions::new(&bucket, None);
+        action.with_prefix("photos/");
+        action.with_max_keys(1);
+
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?encoding-type=url&max-keys=1&prefix=photos%2F&versions=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn page_boundary() {
+        let first = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <ListVersionsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>examplebucket</Name>
+            <Prefix></Prefix>
+            <KeyMarker></KeyMarker>
+            <VersionIdMarker></VersionIdMarker>
+            <NextKeyMarker>hello+world%2B.txt</NextKeyMarker>
+            <NextVersionIdMarker>3/L4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY+MTRCxf3vjVBH40Nr8X8gdRQBpUMLUo</NextVersionIdMarker>
+            <MaxKeys>1</MaxKeys>
+            <EncodingType>url</EncodingType>
+            <IsTruncated>true</IsTruncated>
+            <Version>
+                <Key>hello+world%2B.txt</Key>
+                <VersionId>3/L4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY+MTRCxf3vjVBH40Nr8X8gdRQBpUMLUo</VersionId>
+                <IsLatest>true</IsLatest>
+                <LastModified>2009-10-12T17:50:30.000Z</LastModified>
+                <ETag>&quot;fba9dede5f27731c9771645a39863328&quot;</ETag>
+                <Size>434234</Size>
+                <StorageClass>STANDARD</StorageClass>
+            </Version>
+        </ListVersionsResult>
+        "#;
+
+        let parsed = ListObjectVersions::parse_response(first).unwrap();
+        assert_eq!(parsed.versions.len(), 1);
+        let version = &parsed.versions[0];
+        assert_eq!(version.key, "hello world+.txt");
+        assert_eq!(
+            version.version_id,
+            "3/L4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY+MTRCxf3vjVBH40Nr8X8gdRQBpUMLUo"
+        );
+        assert!(version.is_latest);
+        assert_eq!(version.size, 434_234);
+        assert_eq!(parsed.next_key_marker.as_deref(), Some("hello world+.txt"));
+        assert_eq!(
+            parsed.next_version_id_marker.as_deref(),
+            Some("3/L4kqtJlcpXroDTDmJ+rmSpXd3dIbrHY+MTRCxf3vjVBH40Nr8X8gdRQBpUMLUo")
+        );
+
+        let bucket = bucket();
+        let mut action = ListObjectVersions::new(&bucket, None);
+        action.with_max_keys(1);
+        let next = action.next_page(
+            parsed.next_key_marker.as_deref().unwrap(),
+            parsed.next_version_id_marker.as_deref(),
+        );
+        let url = next.sign(Duration::from_secs(86400));
+        let expected = "https://examplebucket.s3.amazonaws.com/?encoding-type=url&key-marker=hello+world%2B.txt&max-keys=1&version-id-marker=3%2FL4kqtJlcpXroDTDmJ%2BrmSpXd3dIbrHY%2BMTRCxf3vjVBH40Nr8X8gdRQBpUMLUo&versions=";
+        assert_eq!(expected, url.as_str());
+
+        let last = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <ListVersionsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>examplebucket</Name>
+            <KeyMarker>hello+world%2B.txt</KeyMarker>
+            <MaxKeys>1</MaxKeys>
+            <EncodingType>url</EncodingType>
+            <IsTruncated>false</IsTruncated>
+            <DeleteMarker>
+                <Key>my+image.jpg</Key>
+                <VersionId>03jpff543dhffds434rfdsFDN943fdsFkdmqnh892</VersionId>
+                <IsLatest>true</IsLatest>
+                <LastModified>2009-11-12T17:50:30.000Z</LastModified>
+            </DeleteMarker>
+        </ListVersionsResult>
+        "#;
+
+        let parsed = ListObjectVersions::parse_response(last).unwrap();
+        assert!(parsed.versions.is_empty());
+        assert_eq!(parsed.delete_markers.len(), 1);
+        assert_eq!(parsed.delete_markers[0].key, "my image.jpg");
+        assert!(parsed.next_key_marker.is_none());
+        assert!(parsed.next_version_id_marker.is_none());
+    }
+}
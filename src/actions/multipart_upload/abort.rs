@@ -1,11 +1,13 @@
+use std::borrow::Cow;
 use std::iter;
 use std::time::Duration;
 
 use time::OffsetDateTime;
 use url::Url;
 
-use crate::actions::Method;
 use crate::actions::S3Action;
+use crate::actions::{DeleteOutcome, Method};
+use crate::credentials::IntoCredentials;
 use crate::signing::sign;
 use crate::sorting_iter::SortingIterator;
 use crate::{Bucket, Credentials, Map};
@@ -14,6 +16,10 @@ use crate::{Bucket, Credentials, Map};
 ///
 /// This also cleans up any previously uploaded part.
 ///
+/// Aborting an upload which has already been aborted or completed
+/// results in a `404 Not Found`. Use [`AbortMultipartUpload::classify_response`]
+/// to treat it as already absent.
+///
 /// Find out more about `AbortMultipartUpload` from the [AWS API Reference][api]
 ///
 /// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_AbortMultipartUpload.html
@@ -21,7 +27,7 @@ use crate::{Bucket, Credentials, Map};
 #[derive(Debug, Clone)]
 pub struct AbortMultipartUpload<'a> {
     bucket: &'a Bucket,
-    credentials: Option<&'a Credentials>,
+    credentials: Option<Cow<'a, Credentials>>,
     object: &'a str,
 
     upload_id: &'a str,
@@ -33,15 +39,15 @@ pub struct AbortMultipartUpload<'a> {
 impl<'a> AbortMultipartUpload<'a> {
     #[inline]
     #[must_use]
-    pub const fn new(
+    pub fn new(
         bucket: &'a Bucket,
-        credentials: Option<&'a Credentials>,
+        credentials: impl IntoCredentials<'a>,
         object: &'a str,
         upload_id: &'a str,
     ) -> Self {
         Self {
             bucket,
-            credentials,
+            credentials: credentials.into_credentials(),
             object,
 
             upload_id,
@@ -50,6 +56,14 @@ impl<'a> AbortMultipartUpload<'a> {
             headers: Map::new(),
         }
     }
+
+    /// Classify the status code of the response from S3
+    ///
+    /// A `404 Not Found` means that the upload doesn't exist anymore.
+    #[must_use]
+    pub const fn classify_response(status: u16) -> DeleteOutcome {
+        DeleteOutcome::from_status(status)
+    }
 }
 
 impl<'a> S3Action<'a> for AbortMultipartUpload<'a> {
@@ -63,11 +77,15 @@ impl<'a> S3Action<'a> for AbortMultipartUpload<'a> {
         &mut self.headers
     }
 
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
     fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
         let url = self.bucket.object_url(self.object).unwrap();
         let query = iter::once(("uploadId", self.upload_id));
 
-        match self.credentials {
+        match self.credentials.as_deref() {
             Some(credentials) => sign(
                 time,
                 Self::METHOD,
@@ -76,6 +94,7 @@ impl<'a> S3Action<'a> for AbortMultipartUpload<'a> {
                 credentials.secret(),
                 credentials.token(),
                 self.bucket.region(),
+                self.bucket.service(),
                 expires_in.as_secs(),
                 SortingIterator::new(query, self.query.iter()),
                 self.headers.iter(),
@@ -140,4 +159,25 @@ mod tests {
 
         assert_eq!(expected, url.as_str());
     }
+
+    #[test]
+    fn classify_response() {
+        assert_eq!(
+            AbortMultipartUpload::classify_response(204),
+            DeleteOutcome::Deleted
+        );
+        // `NoSuchUpload`, on both AWS and MinIO
+        assert_eq!(
+            AbortMultipartUpload::classify_response(404),
+            DeleteOutcome::AlreadyAbsent
+        );
+        assert_eq!(
+            AbortMultipartUpload::classify_response(403),
+            DeleteOutcome::Failed
+        );
+
+        assert!(AbortMultipartUpload::classify_response(204).is_gone());
+        assert!(AbortMultipartUpload::classify_response(404).is_gone());
+        assert!(!AbortMultipartUpload::classify_response(403).is_gone());
+    }
 }
@@ -0,0 +1,166 @@
+use std::borrow::Cow;
+use std::fmt::{self, Debug, Formatter};
+
+use crate::actions::AbortMultipartUpload;
+use crate::credentials::{IntoCredentials, ResolvedCredentials};
+use crate::{Bucket, Credentials};
+
+/// Guard against leaking a multipart upload
+///
+/// Unless [`MultipartUploadGuard::commit`] is called, for example because an
+/// error interrupted the upload, dropping the guard calls `on_abort` with the
+/// [`AbortMultipartUpload`] action of the upload. Since this crate doesn't do
+/// any IO, `on_abort` is responsible for sending it, or for scheduling it to
+/// be sent later.
+///
+/// ```rust
+/// # use rusty_s3::{Bucket, UrlStyle};
+/// # use rusty_s3::actions::MultipartUploadGuard;
+/// # let bucket = Bucket::new("https://s3.amazonaws.com".parse().unwrap(), UrlStyle::VirtualHost, "examplebucket", "us-east-1").unwrap();
+/// let mut to_abort = Vec::new();
+/// {
+///     let guard = MultipartUploadGuard::new(&bucket, None, "duck.jpg", "abcd", |action| {
+///         to_abort.push(action);
+///     });
+///     // an error happens before `guard.commit()`
+/// }
+/// assert_eq!(to_abort.len(), 1);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[must_use = "dropping the guard immediately aborts the multipart upload"]
+pub struct MultipartUploadGuard<'a, F>
+where
+    F: FnOnce(AbortMultipartUpload<'a>),
+{
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    object: &'a str,
+    upload_id: &'a str,
+
+    on_abort: Option<F>,
+}
+
+impl<'a, F> MultipartUploadGuard<'a, F>
+where
+    F: FnOnce(AbortMultipartUpload<'a>),
+{
+    /// Construct a new `MultipartUploadGuard` for the upload `upload_id`,
+    /// calling `on_abort` if it's dropped without being committed
+    #[inline]
+    pub fn new(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        object: &'a str,
+        upload_id: &'a str,
+        on_abort: F,
+    ) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            object,
+            upload_id,
+
+            on_abort: Some(on_abort),
+        }
+    }
+
+    /// Get the id of the guarded upload
+    #[inline]
+    #[must_use]
+    pub const fn upload_id(&self) -> &'a str {
+        self.upload_id
+    }
+
+    /// Mark the upload as completed, so that dropping the guard doesn't abort it
+    #[inline]
+    pub fn commit(mut self) {
+        self.on_abort = None;
+    }
+}
+
+impl<'a, F> Drop for MultipartUploadGuard<'a, F>
+where
+    F: FnOnce(AbortMultipartUpload<'a>),
+{
+    fn drop(&mut self) {
+        if let Some(on_abort) = self.on_abort.take() {
+            on_abort(AbortMultipartUpload::new(
+                self.bucket,
+                ResolvedCredentials(self.credentials.take()),
+                self.object,
+                self.upload_id,
+            ));
+        }
+    }
+}
+
+impl<'a, F> Debug for MultipartUploadGuard<'a, F>
+where
+    F: FnOnce(AbortMultipartUpload<'a>),
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultipartUploadGuard")
+            .field("bucket", &self.bucket)
+            .field("object", &self.object)
+            .field("upload_id", &self.upload_id)
+            .field("committed", &self.on_abort.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::actions::S3Action as _;
+    use crate::UrlStyle;
+
+    #[test]
+    fn drop_without_commit() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut aborted = None;
+        {
+            let guard = MultipartUploadGuard::new(&bucket, None, "test.txt", "abcd", |action| {
+                aborted = Some(action.sign(Duration::from_secs(86400)));
+            });
+            assert_eq!(guard.upload_id(), "abcd");
+        }
+
+        assert_eq!(
+            aborted.unwrap().as_str(),
+            "https://examplebucket.s3.amazonaws.com/test.txt?uploadId=abcd"
+        );
+    }
+
+    #[test]
+    fn commit() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let aborted = Cell::new(false);
+        let guard = MultipartUploadGuard::new(&bucket, None, "test.txt", "abcd", |_| {
+            aborted.set(true);
+        });
+        guard.commit();
+
+        assert!(!aborted.get());
+    }
+}
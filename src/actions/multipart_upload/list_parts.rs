@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::io::BufRead;
 use std::iter;
 use std::time::Duration;
@@ -8,6 +9,7 @@ use url::Url;
 
 use crate::actions::Method;
 use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
 use crate::signing::sign;
 use crate::sorting_iter::SortingIterator;
 use crate::{Bucket, Credentials, Map};
@@ -25,7 +27,7 @@ use crate::{Bucket, Credentials, Map};
 #[derive(Debug, Clone)]
 pub struct ListParts<'a> {
     bucket: &'a Bucket,
-    credentials: Option<&'a Credentials>,
+    credentials: Option<Cow<'a, Credentials>>,
     object: &'a str,
     upload_id: &'a str,
 
@@ -61,15 +63,15 @@ pub struct PartsContent {
 
 impl<'a> ListParts<'a> {
     #[must_use]
-    pub const fn new(
+    pub fn new(
         bucket: &'a Bucket,
-        credentials: Option<&'a Credentials>,
+        credentials: impl IntoCredentials<'a>,
         object: &'a str,
         upload_id: &'a str,
     ) -> Self {
         Self {
             bucket,
-            credentials,
+            credentials: credentials.into_credentials(),
             object,
             upload_id,
 
@@ -123,12 +125,16 @@ impl<'a> S3Action<'a> for ListParts<'a> {
         &mut self.headers
     }
 
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
     fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
         let url = self.bucket.object_url(self.object).unwrap();
         let query =
             SortingIterator::new(iter::once(("uploadId", self.upload_id)), self.query.iter());
 
-        match self.credentials {
+        match self.credentials.as_deref() {
             Some(credentials) => sign(
                 time,
                 Self::METHOD,
@@ -137,6 +143,7 @@ impl<'a> S3Action<'a> for ListParts<'a> {
                 credentials.secret(),
                 credentials.token(),
                 self.bucket.region(),
+                self.bucket.service(),
                 expires_in.as_secs(),
                 query,
                 self.headers.iter(),
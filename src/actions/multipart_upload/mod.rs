@@ -3,6 +3,11 @@ pub mod abort;
 pub mod complete;
 #[cfg(feature = "full")]
 pub mod create;
+pub mod guard;
+#[cfg(feature = "full")]
+pub mod list_multipart_uploads;
 #[cfg(feature = "full")]
 pub mod list_parts;
+#[cfg(feature = "full")]
+pub mod plan;
 pub mod upload;
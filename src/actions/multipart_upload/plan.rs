@@ -0,0 +1,350 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::actions::{CompleteMultipartUpload, CreateMultipartUpload, PutObject, UploadPart};
+use crate::credentials::{IntoCredentials, ResolvedCredentials};
+use crate::{Bucket, Credentials};
+
+/// The default content length from which [`UploadPlan::new`] uses a multipart upload,
+/// which is also the maximum size of a single `PutObject`
+pub const DEFAULT_MULTIPART_THRESHOLD: u64 = 5 * 1024 * 1024 * 1024;
+
+/// The smallest part size allowed by S3, except for the last part
+const MIN_PART_SIZE: u64 = 5 * 1024 * 1024;
+/// The part size used by [`MultipartPlan`], unless the upload would need too many parts
+const DEFAULT_PART_SIZE: u64 = 8 * 1024 * 1024;
+/// The biggest part size allowed by S3
+const MAX_PART_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+/// The maximum number of parts of a multipart upload
+const MAX_PARTS: u64 = 10_000;
+/// The biggest object which can be uploaded by a [`MultipartPlan`]:
+/// 10,000 parts of 5 GiB each
+pub const MAX_MULTIPART_CONTENT_LENGTH: u64 = MAX_PARTS * MAX_PART_SIZE;
+
+/// Decide how to upload an object, given its content length
+///
+/// Objects smaller than the threshold are uploaded using a single [`PutObject`],
+/// bigger ones using a multipart upload, driven by a [`MultipartPlan`].
+#[derive(Debug, Clone)]
+pub enum UploadPlan<'a> {
+    /// Upload the object using a single `PUT` request
+    Single(PutObject<'a>),
+    /// Upload the object using a multipart upload
+    Multipart(MultipartPlan<'a>),
+}
+
+impl<'a> UploadPlan<'a> {
+    /// Plan the upload of `object`, using a multipart upload
+    /// if `content_length` is at least [`DEFAULT_MULTIPART_THRESHOLD`]
+    ///
+    /// # Panics
+    ///
+    /// If `content_length` is bigger than [`MAX_MULTIPART_CONTENT_LENGTH`]
+    #[must_use]
+    pub fn new(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        object: &'a str,
+        content_length: u64,
+    ) -> Self {
+        Self::with_threshold(
+            bucket,
+            credentials,
+            object,
+            content_length,
+            DEFAULT_MULTIPART_THRESHOLD,
+        )
+    }
+
+    /// Plan the upload of `object`, using a multipart upload
+    /// if `content_length` is at least `threshold`
+    ///
+    /// A `threshold` bigger than [`DEFAULT_MULTIPART_THRESHOLD`] is lowered to it,
+    /// since S3 rejects bigger `PutObject` requests.
+    ///
+    /// # Panics
+    ///
+    /// If `content_length` is bigger than [`MAX_MULTIPART_CONTENT_LENGTH`]
+    #[must_use]
+    pub fn with_threshold(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        object: &'a str,
+        content_length: u64,
+        threshold: u64,
+    ) -> Self {
+        if content_length < threshold.min(DEFAULT_MULTIPART_THRESHOLD) {
+            Self::Single(PutObject::new(bucket, credentials, object))
+        } else {
+            Self::Multipart(MultipartPlan::new(
+                bucket,
+                credentials,
+                object,
+                content_length,
+            ))
+        }
+    }
+}
+
+/// The plan of a multipart upload
+///
+/// 1. Send [`MultipartPlan::create`] and parse the `upload_id` from its response
+/// 2. Send an [`MultipartPlan::upload_part`] for every part returned by
+///    [`MultipartPlan::parts`], with the corresponding range of the body,
+///    collecting the `ETag` header of each response
+/// 3. Send [`MultipartPlan::complete`] with the collected etags, in order
+#[derive(Debug, Clone)]
+pub struct MultipartPlan<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    object: &'a str,
+
+    content_length: u64,
+    part_size: u64,
+}
+
+impl<'a> MultipartPlan<'a> {
+    /// Plan the multipart upload of `object`, splitting `content_length`
+    /// into at most 10,000 parts
+    ///
+    /// # Panics
+    ///
+    /// If `content_length` is bigger than [`MAX_MULTIPART_CONTENT_LENGTH`]
+    #[must_use]
+    pub fn new(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        object: &'a str,
+        content_length: u64,
+    ) -> Self {
+        assert!(
+            content_length <= MAX_MULTIPART_CONTENT_LENGTH,
+            "content_length is too big for a multipart upload"
+        );
+        let min_part_size = div_ceil(content_length, MAX_PARTS);
+        let part_size = DEFAULT_PART_SIZE.max(min_part_size);
+
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            object,
+
+            content_length,
+            part_size,
+        }
+    }
+
+    /// Get the size of every part, except for the last one which may be smaller
+    #[must_use]
+    pub const fn part_size(&self) -> u64 {
+        self.part_size
+    }
+
+    /// Get the part numbers and the byte ranges of the body of every part
+    #[must_use]
+    pub fn parts(&self) -> PartRanges {
+        PartRanges::new(self.content_length, self.part_size)
+    }
+
+    /// The action creating the multipart upload
+    #[must_use]
+    pub fn create(&self) -> CreateMultipartUpload<'a> {
+        CreateMultipartUpload::new(
+            self.bucket,
+            ResolvedCredentials(self.credentials.clone()),
+            self.object,
+        )
+    }
+
+    /// The action uploading the part `part_number`
+    #[must_use]
+    pub fn upload_part(&self, part_number: u16, upload_id: &'a str) -> UploadPart<'a> {
+        UploadPart::new(
+            self.bucket,
+            ResolvedCredentials(self.credentials.clone()),
+            self.object,
+            part_number,
+            upload_id,
+        )
+    }
+
+    /// The action completing the multipart upload
+    pub fn complete<I>(&self, upload_id: &'a str, etags: I) -> CompleteMultipartUpload<'a, I>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        CompleteMultipartUpload::new(
+            self.bucket,
+            ResolvedCredentials(self.credentials.clone()),
+            self.object,
+            upload_id,
+            etags,
+        )
+    }
+}
+
+/// Split a body into parts, yielding the part number and the byte range of every part
+///
+/// Part numbers start from 1. Every part is `part_size` long, except for
+/// the last one which may be shorter. An empty body is made of a single empty part.
+#[derive(Debug, Clone)]
+pub struct PartRanges {
+    content_length: u64,
+    part_size: u64,
+    next: u64,
+}
+
+impl PartRanges {
+    /// Construct a new `PartRanges`
+    ///
+    /// # Panics
+    ///
+    /// If `part_size` is smaller than the 5 MiB minimum allowed by S3, or if
+    /// splitting `content_length` into parts of `part_size` would need more
+    /// than the 10,000 parts allowed by S3
+    #[must_use]
+    pub fn new(content_length: u64, part_size: u64) -> Self {
+        assert!(part_size >= MIN_PART_SIZE, "part_size is too small");
+        let parts = div_ceil(content_length, part_size);
+        assert!(
+            parts <= MAX_PARTS,
+            "part_size is too small for content_length"
+        );
+
+        Self {
+            content_length,
+            part_size,
+            next: 0,
+        }
+    }
+}
+
+impl Iterator for PartRanges {
+    type Item = (u16, Range<u64>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next * self.part_size;
+        if start >= self.content_length && !(self.next == 0 && self.content_length == 0) {
+            return None;
+        }
+
+        self.next += 1;
+        let end = (start + self.part_size).min(self.content_length);
+        let part_number = u16::try_from(self.next).expect("parts are at most 10,000");
+        Some((part_number, start..end))
+    }
+}
+
+/// `u64::div_ceil` is only stable since Rust 1.73
+const fn div_ceil(lhs: u64, rhs: u64) -> u64 {
+    lhs / rhs + (lhs % rhs != 0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    const MIB: u64 = 1024 * 1024;
+
+    fn bucket() -> Bucket {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn below_threshold() {
+        let bucket = bucket();
+
+        let plan = UploadPlan::new(&bucket, None, "test.txt", 1024);
+        assert!(matches!(plan, UploadPlan::Single(_)));
+
+        let plan = UploadPlan::with_threshold(&bucket, None, "test.txt", 20 * MIB - 1, 20 * MIB);
+        assert!(matches!(plan, UploadPlan::Single(_)));
+    }
+
+    #[test]
+    fn above_threshold() {
+        let bucket = bucket();
+
+        let plan = UploadPlan::with_threshold(&bucket, None, "test.txt", 20 * MIB, 20 * MIB);
+        let plan = match plan {
+            UploadPlan::Multipart(plan) => plan,
+            UploadPlan::Single(_) => panic!("expected a multipart upload"),
+        };
+        assert_eq!(plan.part_size(), 8 * MIB);
+
+        let parts = plan.parts().collect::<Vec<_>>();
+        assert_eq!(
+            parts,
+            [
+                (1, 0..8 * MIB),
+                (2, 8 * MIB..16 * MIB),
+                (3, 16 * MIB..20 * MIB)
+            ]
+        );
+
+        let plan = UploadPlan::new(&bucket, None, "test.txt", DEFAULT_MULTIPART_THRESHOLD);
+        assert!(matches!(plan, UploadPlan::Multipart(_)));
+
+        // thresholds above the `PutObject` limit are lowered to it
+        let plan = UploadPlan::with_threshold(
+            &bucket,
+            None,
+            "test.txt",
+            DEFAULT_MULTIPART_THRESHOLD,
+            u64::MAX,
+        );
+        assert!(matches!(plan, UploadPlan::Multipart(_)));
+    }
+
+    #[test]
+    fn max_parts() {
+        let bucket = bucket();
+
+        // 1 TiB
+        let content_length = 1024 * 1024 * MIB;
+        let plan = MultipartPlan::new(&bucket, None, "test.txt", content_length);
+        assert!(plan.part_size() > 8 * MIB);
+
+        let (last_part_number, last_range) = plan.parts().last().unwrap();
+        assert!(last_part_number <= 10_000);
+        assert_eq!(last_range.end, content_length);
+
+        let plan = MultipartPlan::new(&bucket, None, "test.txt", MAX_MULTIPART_CONTENT_LENGTH);
+        assert_eq!(plan.part_size(), 5 * 1024 * MIB);
+        assert_eq!(plan.parts().count(), 10_000);
+    }
+
+    #[test]
+    #[should_panic = "content_length is too big for a multipart upload"]
+    fn too_big() {
+        let _ = MultipartPlan::new(&bucket(), None, "test.txt", u64::MAX);
+    }
+
+    #[test]
+    fn part_ranges() {
+        let parts = PartRanges::new(10 * MIB, 5 * MIB).collect::<Vec<_>>();
+        assert_eq!(parts, [(1, 0..5 * MIB), (2, 5 * MIB..10 * MIB)]);
+
+        let parts = PartRanges::new(0, 5 * MIB).collect::<Vec<_>>();
+        assert_eq!(parts, [(1, 0..0)]);
+
+        let parts = PartRanges::new(10_000 * 5 * MIB, 5 * MIB);
+        assert_eq!(parts.last().unwrap().0, 10_000);
+    }
+
+    #[test]
+    #[should_panic = "part_size is too small for content_length"]
+    fn part_ranges_too_many_parts() {
+        let _ = PartRanges::new(10_000 * 5 * MIB + 1, 5 * MIB);
+    }
+}
@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+use std::iter;
+use std::time::Duration;
+
+use md5::{Digest as _, Md5};
+use serde::Serialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::{CorsRule, Method, S3Action};
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Replace the CORS configuration of a bucket.
+///
+/// Find out more about `PutBucketCors` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutBucketCors.html
+#[derive(Debug, Clone)]
+pub struct PutBucketCors<'a, I> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    rules: I,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+impl<'a, I> PutBucketCors<'a, I> {
+    #[inline]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>, rules: I) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            rules,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+}
+
+impl<'a, I> PutBucketCors<'a, I>
+where
+    I: Iterator<Item = &'a CorsRule>,
+{
+    /// Generate the XML body for the request, together with its `Content-MD5`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body cannot be serialized.
+    pub fn body_with_md5(self) -> (String, String) {
+        #[derive(Serialize)]
+        #[serde(rename = "CORSConfiguration")]
+        struct CorsConfiguration<'a> {
+            #[serde(rename = "CORSRule")]
+            rules: Vec<&'a CorsRule>,
+        }
+
+        let req = CorsConfiguration {
+            rules: self.rules.collect(),
+        };
+
+        let body = quick_xml::se::to_string(&req).unwrap();
+
+        let content_md5 = crate::base64::encode(Md5::digest(body.as_bytes()));
+        (body, content_md5)
+    }
+}
+
+impl<'a, I> S3Action<'a> for PutBucketCors<'a, I>
+where
+    I: Iterator<Item = &'a CorsRule>,
+{
+    const METHOD: Method = Method::Put;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("cors", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::actions::GetBucketCors;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let rules = [CorsRule::default()];
+        let action = PutBucketCors::new(&bucket, None, rules.iter());
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?cors=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let rules = [
+            CorsRule {
+                id: Some("uploads".to_owned()),
+                allowed_headers: vec!["*".to_owned()],
+                allowed_methods: vec!["PUT".to_owned(), "POST".to_owned()],
+                allowed_origins: vec!["https://www.example.com".to_owned()],
+                expose_headers: vec!["ETag".to_owned()],
+                max_age_seconds: Some(3000),
+            },
+            CorsRule {
+                allowed_methods: vec!["GET".to_owned()],
+                allowed_origins: vec!["*".to_owned()],
+                ..Default::default()
+            },
+        ];
+        let action = PutBucketCors::new(&bucket, None, rules.iter());
+        let (body, content_md5) = action.body_with_md5();
+
+        let expected = "<CORSConfiguration>\
+            <CORSRule>\
+                <ID>uploads</ID>\
+                <AllowedHeader>*</AllowedHeader>\
+                <AllowedMethod>PUT</AllowedMethod>\
+                <AllowedMethod>POST</AllowedMethod>\
+                <AllowedOrigin>https://www.example.com</AllowedOrigin>\
+                <ExposeHeader>ETag</ExposeHeader>\
+                <MaxAgeSeconds>3000</MaxAgeSeconds>\
+            </CORSRule>\
+            <CORSRule>\
+                <AllowedMethod>GET</AllowedMethod>\
+                <AllowedOrigin>*</AllowedOrigin>\
+            </CORSRule>\
+        </CORSConfiguration>";
+        assert_eq!(body, expected);
+        assert_eq!(
+            content_md5,
+            crate::base64::encode(Md5::digest(expected.as_bytes()))
+        );
+
+        let parsed = GetBucketCors::parse_response(&body).unwrap();
+        assert_eq!(parsed.rules, rules);
+    }
+}
@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+use std::iter;
+use std::time::Duration;
+
+use md5::{Digest as _, Md5};
+use serde::Serialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::{Method, S3Action, Tag};
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Replace the tags of a bucket.
+///
+/// Find out more about `PutBucketTagging` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutBucketTagging.html
+#[derive(Debug, Clone)]
+pub struct PutBucketTagging<'a, I> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    tags: I,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+impl<'a, I> PutBucketTagging<'a, I> {
+    #[inline]
+    pub fn new(bucket: &'a Bucket, credentials: impl IntoCredentials<'a>, tags: I) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            tags,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+}
+
+impl<'a, I> PutBucketTagging<'a, I>
+where
+    I: Iterator<Item = &'a Tag>,
+{
+    /// Generate the XML body for the request, together with its `Content-MD5`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body cannot be serialized.
+    pub fn body_with_md5(self) -> (String, String) {
+        #[derive(Serialize)]
+        #[serde(rename = "Tagging")]
+        struct Tagging<'a> {
+            #[serde(rename = "TagSet")]
+            tag_set: TagSet<'a>,
+        }
+
+        #[derive(Serialize)]
+        struct TagSet<'a> {
+            #[serde(rename = "Tag")]
+            tags: Vec<&'a Tag>,
+        }
+
+        let req = Tagging {
+            tag_set: TagSet {
+                tags: self.tags.collect(),
+            },
+        };
+
+        let body = quick_xml::se::to_string(&req).unwrap();
+
+        let content_md5 = crate::base64::encode(Md5::digest(body.as_bytes()));
+        (body, content_md5)
+    }
+}
+
+impl<'a, I> S3Action<'a> for PutBucketTagging<'a, I>
+where
+    I: Iterator<Item = &'a Tag>,
+{
+    const METHOD: Method = Method::Put;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("tagging", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::actions::GetBucketTagging;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let tags = [Tag::default()];
+        let action = PutBucketTagging::new(&bucket, None, tags.iter());
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?tagging=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let tags = [
+            Tag {
+                key: "cost-center".to_owned(),
+                value: "1234".to_owned(),
+            },
+            Tag {
+                key: "team".to_owned(),
+                value: "a&b".to_owned(),
+            },
+        ];
+        let action = PutBucketTagging::new(&bucket, None, tags.iter());
+        let (body, content_md5) = action.body_with_md5();
+
+        let expected = "<Tagging>\
+            <TagSet>\
+                <Tag><Key>cost-center</Key><Value>1234</Value></Tag>\
+                <Tag><Key>team</Key><Value>a&amp;b</Value></Tag>\
+            </TagSet>\
+        </Tagging>";
+        assert_eq!(body, expected);
+        assert_eq!(
+            content_md5,
+            crate::base64::encode(Md5::digest(expected.as_bytes()))
+        );
+
+        let parsed = GetBucketTagging::parse_response(&body).unwrap();
+        assert_eq!(parsed.tags(), tags);
+    }
+}
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+use std::iter;
+use std::time::Duration;
+
+use serde::Serialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::{BucketVersioningStatus, Method, S3Action};
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Set the versioning state of a bucket.
+///
+/// Find out more about `PutBucketVersioning` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutBucketVersioning.html
+#[derive(Debug, Clone)]
+pub struct PutBucketVersioning<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    status: BucketVersioningStatus,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+impl<'a> PutBucketVersioning<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        status: BucketVersioningStatus,
+    ) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            status,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Generate the XML body for the request.
+    ///
+    /// [`BucketVersioningStatus::Unset`] omits the `Status`,
+    /// leaving the versioning state of the bucket unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body cannot be serialized.
+    #[must_use]
+    pub fn body(&self) -> String {
+        #[derive(Serialize)]
+        #[serde(rename = "VersioningConfiguration")]
+        struct VersioningConfiguration {
+            #[serde(rename = "@xmlns")]
+            xmlns: &'static str,
+            #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+            status: Option<&'static str>,
+        }
+
+        let req = VersioningConfiguration {
+            xmlns: "http://s3.amazonaws.com/doc/2006-03-01/",
+            status: self.status.to_str(),
+        };
+
+        quick_xml::se::to_string(&req).unwrap()
+    }
+}
+
+impl<'a> S3Action<'a> for PutBucketVersioning<'a> {
+    const METHOD: Method = Method::Put;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.base_url().clone();
+        let query = SortingIterator::new(iter::once(("versioning", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    fn bucket() -> Bucket {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+        let bucket = bucket();
+
+        let action = PutBucketVersioning::new(&bucket, None, BucketVersioningStatus::Enabled);
+        let url = action.sign(expires_in);
+        let expected = "https://examplebucket.s3.amazonaws.com/?versioning=";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn body() {
+        let bucket = bucket();
+
+        let action = PutBucketVersioning::new(&bucket, None, BucketVersioningStatus::Enabled);
+        assert_eq!(
+            action.body(),
+            r#"<VersioningConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><Status>Enabled</Status></VersioningConfiguration>"#
+        );
+
+        let action = PutBucketVersioning::new(&bucket, None, BucketVersioningStatus::Suspended);
+        assert_eq!(
+            action.body(),
+            r#"<VersioningConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><Status>Suspended</Status></VersioningConfiguration>"#
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+use std::iter;
+use std::time::Duration;
+
+use md5::{Digest as _, Md5};
+use serde::Serialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::{LegalHoldStatus, Method, S3Action};
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Place or remove an object lock legal hold on an object.
+///
+/// Find out more about `PutObjectLegalHold` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutObjectLegalHold.html
+#[derive(Debug, Clone)]
+pub struct PutObjectLegalHold<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    object: &'a str,
+    status: LegalHoldStatus,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+impl<'a> PutObjectLegalHold<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        object: &'a str,
+        status: LegalHoldStatus,
+    ) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            object,
+            status,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Set the legal hold of the version `version_id` of the object
+    pub fn with_version_id(&mut self, version_id: impl Into<Cow<'a, str>>) {
+        self.query.insert("versionId", version_id);
+    }
+
+    /// Generate the XML body for the request, together with its `Content-MD5`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body cannot be serialized.
+    #[must_use]
+    pub fn body_with_md5(&self) -> (String, String) {
+        #[derive(Serialize)]
+        #[serde(rename = "LegalHold")]
+        struct LegalHold {
+            #[serde(rename = "Status")]
+            status: &'static str,
+        }
+
+        let req = LegalHold {
+            status: self.status.to_str(),
+        };
+
+        let body = quick_xml::se::to_string(&req).unwrap();
+
+        let content_md5 = crate::base64::encode(Md5::digest(body.as_bytes()));
+        (body, content_md5)
+    }
+}
+
+impl<'a> S3Action<'a> for PutObjectLegalHold<'a> {
+    const METHOD: Method = Method::Put;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.object_url(self.object).unwrap();
+        let query = SortingIterator::new(iter::once(("legal-hold", "")), self.query.iter());
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn body() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = PutObjectLegalHold::new(&bucket, None, "report.pdf", LegalHoldStatus::On);
+        action.with_version_id("abcd");
+
+        let url = action.sign(expires_in);
+        let expected =
+            "https://examplebucket.s3.amazonaws.com/report.pdf?legal-hold=&versionId=abcd";
+        assert_eq!(expected, url.as_str());
+
+        let (body, content_md5) = action.body_with_md5();
+        let expected = "<LegalHold><Status>ON</Status></LegalHold>";
+        assert_eq!(body, expected);
+        assert_eq!(
+            content_md5,
+            crate::base64::encode(Md5::digest(expected.as_bytes()))
+        );
+    }
+}
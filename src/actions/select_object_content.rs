@@ -0,0 +1,361 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use serde::Serialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::actions::Method;
+use crate::actions::S3Action;
+use crate::credentials::IntoCredentials;
+use crate::signing::sign;
+use crate::sorting_iter::SortingIterator;
+use crate::{Bucket, Credentials, Map};
+
+/// Filter the content of an object using an SQL expression, through S3 Select.
+///
+/// The response is an event stream, which can be decoded by
+/// [`SelectEventStreamDecoder`](crate::actions::SelectEventStreamDecoder).
+///
+/// Find out more about `SelectObjectContent` from the [AWS API Reference][api]
+///
+/// [api]: https://docs.aws.amazon.com/AmazonS3/latest/API/API_SelectObjectContent.html
+#[derive(Debug, Clone)]
+pub struct SelectObjectContent<'a> {
+    bucket: &'a Bucket,
+    credentials: Option<Cow<'a, Credentials>>,
+    object: &'a str,
+    expression: &'a str,
+    input: SelectInputFormat,
+    output: SelectOutputFormat,
+
+    query: Map<'a>,
+    headers: Map<'a>,
+}
+
+/// The format of the object queried by [`SelectObjectContent`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SelectInputFormat {
+    Csv { file_header_info: CsvFileHeaderInfo },
+    Json(JsonType),
+    Parquet,
+}
+
+/// How the first line of a CSV object is treated
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CsvFileHeaderInfo {
+    /// The first line is a record
+    None,
+    /// The first line is a header, which is skipped
+    Ignore,
+    /// The first line is a header, whose names can be used in the expression
+    Use,
+}
+
+impl CsvFileHeaderInfo {
+    /// Convert this `CsvFileHeaderInfo` into the name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::CsvFileHeaderInfo;
+    /// assert_eq!(CsvFileHeaderInfo::Use.to_str(), "USE");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Ignore => "IGNORE",
+            Self::Use => "USE",
+        }
+    }
+}
+
+/// The layout of a JSON object
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JsonType {
+    /// The object is a single JSON document
+    Document,
+    /// The object contains one JSON document per line
+    Lines,
+}
+
+impl JsonType {
+    /// Convert this `JsonType` into the name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::JsonType;
+    /// assert_eq!(JsonType::Lines.to_str(), "LINES");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Document => "DOCUMENT",
+            Self::Lines => "LINES",
+        }
+    }
+}
+
+/// The format of the records returned by [`SelectObjectContent`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SelectOutputFormat {
+    Csv,
+    Json,
+}
+
+impl<'a> SelectObjectContent<'a> {
+    /// Construct a new `SelectObjectContent`, running the SQL `expression`
+    /// over a CSV object without a header and returning CSV records
+    #[inline]
+    #[must_use]
+    pub fn new(
+        bucket: &'a Bucket,
+        credentials: impl IntoCredentials<'a>,
+        object: &'a str,
+        expression: &'a str,
+    ) -> Self {
+        Self {
+            bucket,
+            credentials: credentials.into_credentials(),
+            object,
+            expression,
+            input: SelectInputFormat::Csv {
+                file_header_info: CsvFileHeaderInfo::None,
+            },
+            output: SelectOutputFormat::Csv,
+
+            query: Map::new(),
+            headers: Map::new(),
+        }
+    }
+
+    /// Set the format of the object
+    pub fn with_input(&mut self, input: SelectInputFormat) {
+        self.input = input;
+    }
+
+    /// Set the format of the returned records
+    pub fn with_output(&mut self, output: SelectOutputFormat) {
+        self.output = output;
+    }
+
+    /// Generate the XML body for the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body cannot be serialized.
+    #[must_use]
+    pub fn body(&self) -> String {
+        #[derive(Serialize)]
+        #[serde(rename = "SelectObjectContentRequest")]
+        struct SelectObjectContentRequest<'a> {
+            #[serde(rename = "Expression")]
+            expression: &'a str,
+            #[serde(rename = "ExpressionType")]
+            expression_type: &'static str,
+            #[serde(rename = "InputSerialization")]
+            input_serialization: InputSerialization,
+            #[serde(rename = "OutputSerialization")]
+            output_serialization: OutputSerialization,
+        }
+
+        #[derive(Serialize)]
+        struct InputSerialization {
+            #[serde(rename = "CSV", skip_serializing_if = "Option::is_none")]
+            csv: Option<CsvInput>,
+            #[serde(rename = "JSON", skip_serializing_if = "Option::is_none")]
+            json: Option<JsonInput>,
+            #[serde(rename = "Parquet", skip_serializing_if = "Option::is_none")]
+            parquet: Option<Empty>,
+        }
+
+        #[derive(Serialize)]
+        struct CsvInput {
+            #[serde(rename = "FileHeaderInfo")]
+            file_header_info: &'static str,
+        }
+
+        #[derive(Serialize)]
+        struct JsonInput {
+            #[serde(rename = "Type")]
+            type_: &'static str,
+        }
+
+        #[derive(Serialize)]
+        struct OutputSerialization {
+            #[serde(rename = "CSV", skip_serializing_if = "Option::is_none")]
+            csv: Option<Empty>,
+            #[serde(rename = "JSON", skip_serializing_if = "Option::is_none")]
+            json: Option<Empty>,
+        }
+
+        #[derive(Serialize)]
+        struct Empty {}
+
+        let mut input_serialization = InputSerialization {
+            csv: None,
+            json: None,
+            parquet: None,
+        };
+        match self.input {
+            SelectInputFormat::Csv { file_header_info } => {
+                input_serialization.csv = Some(CsvInput {
+                    file_header_info: file_header_info.to_str(),
+                });
+            }
+            SelectInputFormat::Json(type_) => {
+                input_serialization.json = Some(JsonInput {
+                    type_: type_.to_str(),
+                });
+            }
+            SelectInputFormat::Parquet => input_serialization.parquet = Some(Empty {}),
+        }
+
+        let output_serialization = match self.output {
+            SelectOutputFormat::Csv => OutputSerialization {
+                csv: Some(Empty {}),
+                json: None,
+            },
+            SelectOutputFormat::Json => OutputSerialization {
+                csv: None,
+                json: Some(Empty {}),
+            },
+        };
+
+        let req = SelectObjectContentRequest {
+            expression: self.expression,
+            expression_type: "SQL",
+            input_serialization,
+            output_serialization,
+        };
+
+        quick_xml::se::to_string(&req).unwrap()
+    }
+}
+
+impl<'a> S3Action<'a> for SelectObjectContent<'a> {
+    const METHOD: Method = Method::Post;
+
+    fn query_mut(&mut self) -> &mut Map<'a> {
+        &mut self.query
+    }
+
+    fn headers_mut(&mut self) -> &mut Map<'a> {
+        &mut self.headers
+    }
+
+    fn headers(&self) -> &Map<'a> {
+        &self.headers
+    }
+
+    fn sign_with_time(&self, expires_in: Duration, time: &OffsetDateTime) -> Url {
+        let url = self.bucket.object_url(self.object).unwrap();
+        let query = SortingIterator::new(
+            [("select", ""), ("select-type", "2")].into_iter(),
+            self.query.iter(),
+        );
+
+        match self.credentials.as_deref() {
+            Some(credentials) => sign(
+                time,
+                Self::METHOD,
+                url,
+                credentials.key(),
+                credentials.secret(),
+                credentials.token(),
+                self.bucket.region(),
+                self.bucket.service(),
+                expires_in.as_secs(),
+                query,
+                self.headers.iter(),
+            ),
+            None => crate::signing::util::add_query_params(url, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::UrlStyle;
+
+    #[test]
+    fn anonymous_custom_query() {
+        let expires_in = Duration::from_secs(86400);
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action =
+            SelectObjectContent::new(&bucket, None, "data.csv", "SELECT * FROM S3Object");
+        action.query_mut().insert("foo", "bar");
+        let url = action.sign(expires_in);
+        let expected =
+            "https://examplebucket.s3.amazonaws.com/data.csv?foo=bar&select=&select-type=2";
+
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[test]
+    fn csv_body() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action =
+            SelectObjectContent::new(&bucket, None, "data.csv", "SELECT * FROM S3Object");
+        action.with_input(SelectInputFormat::Csv {
+            file_header_info: CsvFileHeaderInfo::Use,
+        });
+
+        let expected = "<SelectObjectContentRequest>\
+            <Expression>SELECT * FROM S3Object</Expression>\
+            <ExpressionType>SQL</ExpressionType>\
+            <InputSerialization><CSV><FileHeaderInfo>USE</FileHeaderInfo></CSV></InputSerialization>\
+            <OutputSerialization><CSV/></OutputSerialization>\
+        </SelectObjectContentRequest>";
+        assert_eq!(action.body(), expected);
+    }
+
+    #[test]
+    fn json_body() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = SelectObjectContent::new(
+            &bucket,
+            None,
+            "data.json",
+            "SELECT s.name FROM S3Object s WHERE s.age > 30",
+        );
+        action.with_input(SelectInputFormat::Json(JsonType::Lines));
+        action.with_output(SelectOutputFormat::Json);
+
+        let expected = "<SelectObjectContentRequest>\
+            <Expression>SELECT s.name FROM S3Object s WHERE s.age &gt; 30</Expression>\
+            <ExpressionType>SQL</ExpressionType>\
+            <InputSerialization><JSON><Type>LINES</Type></JSON></InputSerialization>\
+            <OutputSerialization><JSON/></OutputSerialization>\
+        </SelectObjectContentRequest>";
+        assert_eq!(action.body(), expected);
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+
+/// The storage class of an object, determining its availability and pricing.
+///
+/// Find out more about storage classes from the [AWS documentation][docs]
+///
+/// [docs]: https://docs.aws.amazon.com/AmazonS3/latest/userguide/storage-class-intro.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageClass {
+    Standard,
+    StandardIa,
+    OnezoneIa,
+    IntelligentTiering,
+    Glacier,
+    GlacierIr,
+    DeepArchive,
+    ReducedRedundancy,
+}
+
+impl StorageClass {
+    /// Convert this `StorageClass` into the uppercase name used by S3.
+    ///
+    /// ```rust
+    /// # use rusty_s3::actions::StorageClass;
+    /// assert_eq!(StorageClass::StandardIa.to_str(), "STANDARD_IA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Standard => "STANDARD",
+            Self::StandardIa => "STANDARD_IA",
+            Self::OnezoneIa => "ONEZONE_IA",
+            Self::IntelligentTiering => "INTELLIGENT_TIERING",
+            Self::Glacier => "GLACIER",
+            Self::GlacierIr => "GLACIER_IR",
+            Self::DeepArchive => "DEEP_ARCHIVE",
+            Self::ReducedRedundancy => "REDUCED_REDUNDANCY",
+        }
+    }
+}
+
+impl Display for StorageClass {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_str() {
+        assert_eq!(StorageClass::Standard.to_str(), "STANDARD");
+        assert_eq!(StorageClass::StandardIa.to_str(), "STANDARD_IA");
+        assert_eq!(StorageClass::OnezoneIa.to_str(), "ONEZONE_IA");
+        assert_eq!(
+            StorageClass::IntelligentTiering.to_str(),
+            "INTELLIGENT_TIERING"
+        );
+        assert_eq!(StorageClass::Glacier.to_str(), "GLACIER");
+        assert_eq!(StorageClass::GlacierIr.to_str(), "GLACIER_IR");
+        assert_eq!(StorageClass::DeepArchive.to_str(), "DEEP_ARCHIVE");
+        assert_eq!(
+            StorageClass::ReducedRedundancy.to_str(),
+            "REDUCED_REDUNDANCY"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Write as _;
+
+use crate::signing::util::percent_encode;
+
+/// The `x-amz-tagging` header, setting the tags of an object
+pub const TAGGING_HEADER: &str = "x-amz-tagging";
+
+/// Encode `tags` as the value of the `x-amz-tagging` header,
+/// which uses the format of a URL query string
+pub fn tagging_header<'t, I>(tags: I) -> String
+where
+    I: IntoIterator<Item = (&'t str, &'t str)>,
+{
+    let mut header = String::new();
+    for (key, value) in tags {
+        if !header.is_empty() {
+            header.push('&');
+        }
+        let _ = write!(header, "{}={}", percent_encode(key), percent_encode(value));
+    }
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let header = tagging_header([("project", "rusty s3"), ("team", "a&b=c")]);
+        assert_eq!(header, "project=rusty%20s3&team=a%26b%3Dc");
+
+        assert_eq!(tagging_header([]), "");
+    }
+}
@@ -4,33 +4,91 @@
 //! rotate them at any point in the program, keeping all copies of the same
 //! [`RotatingCredentials`] in sync with the latest version.
 //!
+//! [`CredentialsProvider`] abstracts over the sources of [`Credentials`],
+//! like static [`Credentials`] and [`RotatingCredentials`].
+//!
 //! [`Ec2SecurityCredentialsMetadataResponse`] parses the response from the
 //! [EC2 metadata service](https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instancedata-data-retrieval.html),
 //! which provides an endpoint for retrieving credentials using the permissions
 //! for the [attached IAM roles](https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/iam-roles-for-amazon-ec2.html).
+//!
+//! [`Credentials::validate`] checks that [`Credentials`] look like AWS credentials,
+//! catching truncated or swapped keys and secrets.
+//!
+//! [`Credentials::from_profile`] reads credentials from the AWS shared
+//! credentials file, while [`RoleProfile`] reads profiles assuming a role
+//! from the AWS config file.
+//!
+//! With the `serde` feature enabled, [`Credentials`] implement `Serialize`
+//! and `Deserialize`, in order to be stored in a secrets store or read from
+//! configuration files. The serialized form contains the secret in plain text,
+//! so it must be treated as carefully as the secret itself.
+//!
+//! [`AssumeRoleResponse`] parses the response from the STS
+//! [`AssumeRole`](https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRole.html) API.
 
-use std::env;
-use std::fmt::{self, Debug, Formatter};
+use std::env::{self, VarError};
+use std::error::Error as StdError;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::time::Duration;
 
+pub use self::profile::RoleProfile;
+pub(crate) use self::provider::ResolvedCredentials;
+pub use self::provider::{CredentialsProvider, IntoCredentials};
 #[allow(clippy::module_name_repetitions)]
 pub use self::rotating::RotatingCredentials;
 #[cfg(feature = "full")]
 pub use self::serde::Ec2SecurityCredentialsMetadataResponse;
+#[cfg(feature = "full")]
+pub use self::sts::AssumeRoleResponse;
+pub use self::validate::CredentialError;
+use time::OffsetDateTime;
 use zeroize::Zeroizing;
 
+#[cfg(feature = "full")]
+mod process;
+mod profile;
+mod provider;
 mod rotating;
 #[cfg(feature = "full")]
 mod serde;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "full")]
+mod sts;
+mod validate;
 
 /// S3 credentials
+///
+/// With the `serde` feature enabled, `Credentials` can be serialized and
+/// deserialized. The serialized form contains the secret in plain text:
+/// only send it to trusted storage, and never log it. `Debug` never shows
+/// the secret nor the token.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Credentials {
     key: String,
     secret: Zeroizing<String>,
     token: Option<String>,
+    expiration: Option<OffsetDateTime>,
 }
 
 impl Credentials {
+    /// No credentials, for explicitly sending anonymous requests
+    ///
+    /// Actions take an `Option<&Credentials>`, or any other [`IntoCredentials`],
+    /// where `None` produces unsigned urls. Passing `ANONYMOUS` instead of `None` makes it clear
+    /// that the request is meant to be anonymous, and easy to search for.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use rusty_s3::{Bucket, Credentials, S3Action, UrlStyle};
+    /// # let bucket = Bucket::new("https://s3.amazonaws.com".parse().unwrap(), UrlStyle::VirtualHost, "examplebucket", "us-east-1").unwrap();
+    /// let action = bucket.get_object(Credentials::ANONYMOUS, "duck.jpg");
+    /// let url = action.sign(Duration::from_secs(3600));
+    /// assert_eq!(url.as_str(), "https://examplebucket.s3.amazonaws.com/duck.jpg");
+    /// ```
+    pub const ANONYMOUS: Option<&'static Self> = None;
+
     /// Construct a new `Credentials` using the provided key and secret
     #[inline]
     pub fn new(key: impl Into<String>, secret: impl Into<String>) -> Self {
@@ -47,12 +105,28 @@ impl Credentials {
         Self::new_with_maybe_token(key.into(), secret.into(), Some(token.into()))
     }
 
+    /// Construct a new `Credentials` using the provided key, secret and token,
+    /// which are valid until `expiration`
+    #[inline]
+    pub fn new_with_token_and_expiration(
+        key: impl Into<String>,
+        secret: impl Into<String>,
+        token: impl Into<String>,
+        expiration: OffsetDateTime,
+    ) -> Self {
+        let mut credentials =
+            Self::new_with_maybe_token(key.into(), secret.into(), Some(token.into()));
+        credentials.expiration = Some(expiration);
+        credentials
+    }
+
     #[inline]
     pub(super) fn new_with_maybe_token(key: String, secret: String, token: Option<String>) -> Self {
         Self {
             key,
             secret: Zeroizing::new(secret),
             token,
+            expiration: None,
         }
     }
 
@@ -60,14 +134,37 @@ impl Credentials {
     ///
     /// Reads the key from the `AWS_ACCESS_KEY_ID` environment variable and the secret
     /// from the `AWS_SECRET_ACCESS_KEY` environment variable.
-    /// If `AWS_SESSION_TOKEN` is set a token is also read.
+    /// If `AWS_SESSION_TOKEN` is set a token is also read, falling back to the legacy
+    /// `AWS_SECURITY_TOKEN` like the AWS SDKs do.
     /// Returns `None` if either environment variables aren't set or they aren't valid utf-8.
     #[must_use]
     pub fn from_env() -> Option<Self> {
-        let key = env::var("AWS_ACCESS_KEY_ID").ok()?;
-        let secret = env::var("AWS_SECRET_ACCESS_KEY").ok()?;
-        let token = env::var("AWS_SESSION_TOKEN").ok();
-        Some(Self::new_with_maybe_token(key, secret, token))
+        Self::from_env_or_err().ok()
+    }
+
+    /// Construct a new `Credentials` using AWS's default environment variables
+    ///
+    /// Like [`Credentials::from_env`], but describes which environment
+    /// variable is missing or invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `AWS_ACCESS_KEY_ID` or `AWS_SECRET_ACCESS_KEY`
+    /// aren't set or they aren't valid utf-8.
+    pub fn from_env_or_err() -> Result<Self, EnvCredentialsError> {
+        let var = |name| {
+            env::var(name).map_err(|err| match err {
+                VarError::NotPresent => EnvCredentialsError::Missing(name),
+                VarError::NotUnicode(_) => EnvCredentialsError::NotUnicode(name),
+            })
+        };
+
+        let key = var("AWS_ACCESS_KEY_ID")?;
+        let secret = var("AWS_SECRET_ACCESS_KEY")?;
+        let token = env::var("AWS_SESSION_TOKEN")
+            .or_else(|_| env::var("AWS_SECURITY_TOKEN"))
+            .ok();
+        Ok(Self::new_with_maybe_token(key, secret, token))
     }
 
     /// Get the key of this `Credentials`
@@ -90,6 +187,23 @@ impl Credentials {
     pub fn token(&self) -> Option<&str> {
         self.token.as_deref()
     }
+
+    /// Get the expiration of this `Credentials`, if known
+    #[inline]
+    #[must_use]
+    pub const fn expiration(&self) -> Option<OffsetDateTime> {
+        self.expiration
+    }
+
+    /// Returns `true` if this `Credentials` has expired at `now`, or is going to
+    /// expire within `skew`
+    ///
+    /// `Credentials` without an expiration never expire.
+    #[must_use]
+    pub fn is_expired(&self, now: OffsetDateTime, skew: Duration) -> bool {
+        self.expiration
+            .is_some_and(|expiration| expiration <= now + skew)
+    }
 }
 
 impl Debug for Credentials {
@@ -100,11 +214,40 @@ impl Debug for Credentials {
     }
 }
 
+/// An error returned by [`Credentials::from_env_or_err`]
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvCredentialsError {
+    /// The environment variable isn't set
+    Missing(&'static str),
+    /// The environment variable isn't valid utf-8
+    NotUnicode(&'static str),
+}
+
+impl Display for EnvCredentialsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Missing(name) => write!(f, "the `{name}` environment variable isn't set"),
+            Self::NotUnicode(name) => {
+                write!(f, "the `{name}` environment variable isn't valid utf-8")
+            }
+        }
+    }
+}
+
+impl StdError for EnvCredentialsError {}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::{Bucket, S3Action as _, UrlStyle};
+
+    // tests touching the environment variables must not run concurrently
+    pub(super) static ENV_LOCK: Mutex<()> = Mutex::new(());
 
     #[test]
     fn key_secret() {
@@ -112,6 +255,7 @@ mod tests {
         assert_eq!(credentials.key(), "abcd");
         assert_eq!(credentials.secret(), "1234");
         assert!(credentials.token().is_none());
+        assert!(credentials.expiration().is_none());
     }
 
     #[test]
@@ -122,6 +266,62 @@ mod tests {
         assert_eq!(credentials.token(), Some("xyz"));
     }
 
+    #[test]
+    fn expired() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let expiration = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+        let credentials =
+            Credentials::new_with_token_and_expiration("abcd", "1234", "xyz", expiration);
+        assert_eq!(credentials.token(), Some("xyz"));
+        assert_eq!(credentials.expiration(), Some(expiration));
+
+        let now = expiration + Duration::from_secs(1);
+        assert!(credentials.is_expired(now, Duration::ZERO));
+        assert!(credentials.is_expired(expiration, Duration::ZERO));
+    }
+
+    #[test]
+    fn not_expired() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let expiration = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+        let credentials =
+            Credentials::new_with_token_and_expiration("abcd", "1234", "xyz", expiration);
+
+        let now = expiration - Duration::from_secs(600);
+        assert!(!credentials.is_expired(now, Duration::ZERO));
+        assert!(!credentials.is_expired(now, Duration::from_secs(300)));
+        assert!(credentials.is_expired(now, Duration::from_secs(600)));
+
+        let credentials = Credentials::new("abcd", "1234");
+        assert!(!credentials.is_expired(now, Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn anonymous() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+        let expires_in = Duration::from_secs(86400);
+
+        assert_eq!(
+            bucket
+                .get_object(Credentials::ANONYMOUS, "duck.jpg")
+                .sign(expires_in),
+            bucket.get_object(None, "duck.jpg").sign(expires_in)
+        );
+        assert_eq!(
+            bucket
+                .put_object(Credentials::ANONYMOUS, "duck.jpg")
+                .sign(expires_in),
+            bucket.put_object(None, "duck.jpg").sign(expires_in)
+        );
+    }
+
     #[test]
     fn debug() {
         let credentials = Credentials::new("abcd", "1234");
@@ -138,6 +338,8 @@ mod tests {
 
     #[test]
     fn from_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+
         env::set_var("AWS_ACCESS_KEY_ID", "key");
         env::set_var("AWS_SECRET_ACCESS_KEY", "secret");
 
@@ -151,4 +353,55 @@ mod tests {
 
         assert!(Credentials::from_env().is_none());
     }
+
+    #[test]
+    fn from_env_security_token() {
+        let _guard = ENV_LOCK.lock().unwrap();
+
+        env::set_var("AWS_ACCESS_KEY_ID", "key");
+        env::set_var("AWS_SECRET_ACCESS_KEY", "secret");
+        env::set_var("AWS_SECURITY_TOKEN", "legacy");
+
+        let credentials = Credentials::from_env().unwrap();
+        assert_eq!(credentials.token(), Some("legacy"));
+
+        env::set_var("AWS_SESSION_TOKEN", "session");
+
+        let credentials = Credentials::from_env().unwrap();
+        assert_eq!(credentials.token(), Some("session"));
+
+        env::remove_var("AWS_ACCESS_KEY_ID");
+        env::remove_var("AWS_SECRET_ACCESS_KEY");
+        env::remove_var("AWS_SECURITY_TOKEN");
+        env::remove_var("AWS_SESSION_TOKEN");
+    }
+
+    #[test]
+    fn from_env_or_err() {
+        let _guard = ENV_LOCK.lock().unwrap();
+
+        env::remove_var("AWS_ACCESS_KEY_ID");
+        env::remove_var("AWS_SECRET_ACCESS_KEY");
+
+        let err = Credentials::from_env_or_err().unwrap_err();
+        assert_eq!(err, EnvCredentialsError::Missing("AWS_ACCESS_KEY_ID"));
+        assert_eq!(
+            err.to_string(),
+            "the `AWS_ACCESS_KEY_ID` environment variable isn't set"
+        );
+
+        env::set_var("AWS_ACCESS_KEY_ID", "key");
+
+        let err = Credentials::from_env_or_err().unwrap_err();
+        assert_eq!(err, EnvCredentialsError::Missing("AWS_SECRET_ACCESS_KEY"));
+
+        env::set_var("AWS_SECRET_ACCESS_KEY", "secret");
+
+        let credentials = Credentials::from_env_or_err().unwrap();
+        assert_eq!(credentials.key(), "key");
+        assert_eq!(credentials.secret(), "secret");
+
+        env::remove_var("AWS_ACCESS_KEY_ID");
+        env::remove_var("AWS_SECRET_ACCESS_KEY");
+    }
 }
@@ -0,0 +1,380 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::Credentials;
+
+const DEFAULT_PROFILE: &str = "default";
+
+/// A profile of the AWS config file which assumes a role
+///
+/// Profiles with a `role_arn` and a `source_profile` assume the role
+/// using the credentials of the source profile. Call the STS `AssumeRole` API
+/// with `source_credentials` and parse the response with
+/// [`AssumeRoleResponse`](crate::credentials::AssumeRoleResponse).
+///
+/// Only a single level of chaining is supported: the source profile
+/// must contain static credentials.
+#[derive(Debug, Clone)]
+pub struct RoleProfile {
+    /// The ARN of the role to assume
+    pub role_arn: String,
+    /// The credentials of the `source_profile`, used for assuming the role
+    pub source_credentials: Credentials,
+    /// The `external_id` to pass to `AssumeRole`
+    pub external_id: Option<String>,
+    /// The `role_session_name` to pass to `AssumeRole`
+    pub role_session_name: Option<String>,
+}
+
+impl RoleProfile {
+    /// Read a profile assuming a role from the AWS config file
+    ///
+    /// When `profile` is `None` the `AWS_PROFILE` environment variable is used,
+    /// falling back to `default`. When `config_path` is `None` the config is read
+    /// from the `AWS_CONFIG_FILE` environment variable, falling back to
+    /// `~/.aws/config`. The credentials of the source profile are read from the
+    /// shared credentials file, like [`Credentials::from_profile`] does with
+    /// `credentials_path`, falling back to the config file.
+    ///
+    /// Returns `None` if the config file doesn't exist, the profile doesn't exist,
+    /// it doesn't have both a `role_arn` and a `source_profile` or the
+    /// credentials of the source profile can't be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the files can't be read.
+    pub fn from_config(
+        profile: Option<&str>,
+        config_path: Option<&Path>,
+        credentials_path: Option<&Path>,
+    ) -> io::Result<Option<Self>> {
+        let profile = profile_name(profile);
+        let Some(config) = read(config_path, default_config_path)? else {
+            return Ok(None);
+        };
+        let credentials = read(credentials_path, default_path)?;
+
+        Ok(parse_role_profile(
+            &config,
+            credentials.as_deref(),
+            &profile,
+        ))
+    }
+}
+
+impl Credentials {
+    /// Read a profile from the AWS shared credentials file
+    ///
+    /// When `profile` is `None` the `AWS_PROFILE` environment variable is used,
+    /// falling back to `default`. When `path` is `None` the file is read from
+    /// the `AWS_SHARED_CREDENTIALS_FILE` environment variable, falling back
+    /// to `~/.aws/credentials`.
+    ///
+    /// Reads the `aws_access_key_id`, `aws_secret_access_key` and the optional
+    /// `aws_session_token` of the profile.
+    /// Returns `None` if the file doesn't exist, the profile doesn't exist
+    /// or it doesn't have both a key and a secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read.
+    pub fn from_profile(profile: Option<&str>, path: Option<&Path>) -> io::Result<Option<Self>> {
+        let profile = profile_name(profile);
+        let Some(contents) = read(path, default_path)? else {
+            return Ok(None);
+        };
+
+        Ok(parse_profile(&contents, &profile))
+    }
+}
+
+fn profile_name(profile: Option<&str>) -> String {
+    profile.map_or_else(
+        || env::var("AWS_PROFILE").unwrap_or_else(|_| DEFAULT_PROFILE.to_owned()),
+        str::to_owned,
+    )
+}
+
+/// Read the file at `path`, or at `default_path()`, returning `None` if it doesn't exist
+fn read(path: Option<&Path>, default_path: fn() -> Option<PathBuf>) -> io::Result<Option<String>> {
+    let path = match path {
+        Some(path) => path.to_owned(),
+        None => match default_path() {
+            Some(path) => path,
+            None => return Ok(None),
+        },
+    };
+
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AWS_CONFIG_FILE") {
+        return Some(path.into());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(Path::new(&home).join(".aws").join("config"))
+}
+
+fn default_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AWS_SHARED_CREDENTIALS_FILE") {
+        return Some(path.into());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(Path::new(&home).join(".aws").join("credentials"))
+}
+
+fn parse_profile(contents: &str, profile: &str) -> Option<Credentials> {
+    credentials_from(section(contents, |section| section == profile))
+}
+
+fn credentials_from<'c>(pairs: impl Iterator<Item = (&'c str, &'c str)>) -> Option<Credentials> {
+    let mut key = None;
+    let mut secret = None;
+    let mut token = None;
+
+    for (name, value) in pairs {
+        match name {
+            "aws_access_key_id" => key = Some(value.to_owned()),
+            "aws_secret_access_key" => secret = Some(value.to_owned()),
+            "aws_session_token" => token = Some(value.to_owned()),
+            _ => {}
+        }
+    }
+
+    Some(Credentials::new_with_maybe_token(key?, secret?, token))
+}
+
+fn parse_role_profile(
+    config: &str,
+    credentials: Option<&str>,
+    profile: &str,
+) -> Option<RoleProfile> {
+    let mut role_arn = None;
+    let mut source_profile = None;
+    let mut external_id = None;
+    let mut role_session_name = None;
+    for (name, value) in section(config, is_config_profile(profile)) {
+        match name {
+            "role_arn" => role_arn = Some(value.to_owned()),
+            "source_profile" => source_profile = Some(value),
+            "external_id" => external_id = Some(value.to_owned()),
+            "role_session_name" => role_session_name = Some(value.to_owned()),
+            _ => {}
+        }
+    }
+    let role_arn = role_arn?;
+    let source_profile = source_profile?;
+
+    let source_credentials = credentials
+        .and_then(|credentials| parse_profile(credentials, source_profile))
+        .or_else(|| credentials_from(section(config, is_config_profile(source_profile))))?;
+
+    Some(RoleProfile {
+        role_arn,
+        source_credentials,
+        external_id,
+        role_session_name,
+    })
+}
+
+/// Match the section of `profile` in the config file, where profiles
+/// other than `default` are prefixed by `profile`
+fn is_config_profile(profile: &str) -> impl Fn(&str) -> bool + '_ {
+    move |section| {
+        section == profile
+            || section
+                .strip_prefix("profile")
+                .is_some_and(|name| name.starts_with(char::is_whitespace) && name.trim() == profile)
+    }
+}
+
+/// Iterate over the `name = value` pairs of the sections matching `is_section`
+fn section<'c>(
+    contents: &'c str,
+    is_section: impl Fn(&str) -> bool + 'c,
+) -> impl Iterator<Item = (&'c str, &'c str)> + 'c {
+    let mut matching = false;
+    contents.lines().filter_map(move |line| {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            return None;
+        }
+
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            matching = is_section(section.trim());
+            return None;
+        }
+
+        if !matching {
+            return None;
+        }
+
+        line.split_once('=')
+            .map(|(name, value)| (name.trim(), value.trim()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::credentials::tests::ENV_LOCK;
+
+    #[test]
+    fn profiles() {
+        let contents = "\
+# the default profile
+[default]
+aws_access_key_id = default_key
+aws_secret_access_key = default_secret
+
+[dev]
+aws_access_key_id=dev_key
+aws_secret_access_key=dev_secret
+aws_session_token=dev_token
+region = eu-west-1
+
+[incomplete]
+aws_access_key_id = incomplete_key
+";
+        let path = env::temp_dir().join(format!("rusty-s3-credentials-{}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+
+        let credentials = Credentials::from_profile(Some("default"), Some(&path))
+            .unwrap()
+            .unwrap();
+        assert_eq!(credentials.key(), "default_key");
+        assert_eq!(credentials.secret(), "default_secret");
+        assert!(credentials.token().is_none());
+
+        let credentials = Credentials::from_profile(Some("dev"), Some(&path))
+            .unwrap()
+            .unwrap();
+        assert_eq!(credentials.key(), "dev_key");
+        assert_eq!(credentials.secret(), "dev_secret");
+        assert_eq!(credentials.token(), Some("dev_token"));
+
+        assert!(Credentials::from_profile(Some("incomplete"), Some(&path))
+            .unwrap()
+            .is_none());
+        assert!(Credentials::from_profile(Some("missing"), Some(&path))
+            .unwrap()
+            .is_none());
+
+        fs::remove_file(&path).unwrap();
+        assert!(Credentials::from_profile(Some("default"), Some(&path))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn env_fallbacks() {
+        let _guard = ENV_LOCK.lock().unwrap();
+
+        let contents = "\
+[default]
+aws_access_key_id = default_key
+aws_secret_access_key = default_secret
+
+[dev]
+aws_access_key_id = dev_key
+aws_secret_access_key = dev_secret
+";
+        let path = env::temp_dir().join(format!("rusty-s3-credentials-env-{}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+
+        env::set_var("AWS_SHARED_CREDENTIALS_FILE", &path);
+        env::remove_var("AWS_PROFILE");
+
+        let credentials = Credentials::from_profile(None, None).unwrap().unwrap();
+        assert_eq!(credentials.key(), "default_key");
+
+        env::set_var("AWS_PROFILE", "dev");
+        let credentials = Credentials::from_profile(None, None).unwrap().unwrap();
+        assert_eq!(credentials.key(), "dev_key");
+
+        // explicit arguments take precedence over the environment variables
+        let credentials = Credentials::from_profile(Some("default"), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(credentials.key(), "default_key");
+        let missing = env::temp_dir().join(format!(
+            "rusty-s3-credentials-missing-{}",
+            std::process::id()
+        ));
+        assert!(Credentials::from_profile(None, Some(&missing))
+            .unwrap()
+            .is_none());
+
+        fs::remove_file(&path).unwrap();
+        assert!(Credentials::from_profile(None, None).unwrap().is_none());
+
+        env::remove_var("AWS_SHARED_CREDENTIALS_FILE");
+        env::remove_var("AWS_PROFILE");
+    }
+
+    #[test]
+    fn role_profiles() {
+        let config = "\
+[default]
+region = eu-west-1
+
+[profile base]
+aws_access_key_id = config_key
+aws_secret_access_key = config_secret
+
+[profile admin]
+role_arn = arn:aws:iam::123456789012:role/admin
+source_profile = default
+external_id = 12345
+role_session_name = rusty-s3
+
+[profile from-config]
+role_arn = arn:aws:iam::123456789012:role/reader
+source_profile = base
+
+[profile missing-source]
+role_arn = arn:aws:iam::123456789012:role/reader
+source_profile = missing
+
+[profileadmin]
+role_arn = arn:aws:iam::123456789012:role/wrong
+source_profile = default
+";
+        let credentials = "\
+[default]
+aws_access_key_id = default_key
+aws_secret_access_key = default_secret
+";
+
+        let role = parse_role_profile(config, Some(credentials), "admin").unwrap();
+        assert_eq!(role.role_arn, "arn:aws:iam::123456789012:role/admin");
+        assert_eq!(role.source_credentials.key(), "default_key");
+        assert_eq!(role.source_credentials.secret(), "default_secret");
+        assert_eq!(role.external_id.as_deref(), Some("12345"));
+        assert_eq!(role.role_session_name.as_deref(), Some("rusty-s3"));
+
+        let role = parse_role_profile(config, Some(credentials), "from-config").unwrap();
+        assert_eq!(role.role_arn, "arn:aws:iam::123456789012:role/reader");
+        assert_eq!(role.source_credentials.key(), "config_key");
+        assert!(role.external_id.is_none());
+        assert!(role.role_session_name.is_none());
+
+        assert!(parse_role_profile(config, Some(credentials), "missing-source").is_none());
+        assert!(parse_role_profile(config, Some(credentials), "base").is_none());
+        assert!(parse_role_profile(config, Some(credentials), "default").is_none());
+        assert!(parse_role_profile(config, None, "admin").is_none());
+    }
+}
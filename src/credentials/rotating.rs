@@ -20,7 +20,12 @@ impl RotatingCredentials {
     #[must_use]
     pub fn new(key: String, secret: String, token: Option<String>) -> Self {
         let credentials = Credentials::new_with_maybe_token(key, secret, token);
+        Self::from_credentials(credentials)
+    }
 
+    /// Construct a new `RotatingCredentials` starting from `credentials`
+    #[must_use]
+    pub fn from_credentials(credentials: Credentials) -> Self {
         Self {
             inner: Arc::new(RwLock::new(Arc::new(credentials))),
         }
@@ -44,7 +49,18 @@ impl RotatingCredentials {
     /// If the lock is poisoned
     pub fn update(&self, key: String, secret: String, token: Option<String>) {
         let credentials = Credentials::new_with_maybe_token(key, secret, token);
+        self.update_from(credentials);
+    }
 
+    /// Replace the credentials inside this `RotatingCredentials` with `credentials`
+    ///
+    /// If no [`RotatingCredentials::get`] is holding on to them, the previous
+    /// credentials are dropped, and their secret zeroized, before releasing the lock.
+    ///
+    /// # Panics
+    ///
+    /// If the lock is poisoned
+    pub fn update_from(&self, credentials: Credentials) {
         let mut lock = self.inner.write().expect("can't be poisoned");
         match Arc::get_mut(&mut lock) {
             Some(arc) => *arc = credentials,
@@ -148,6 +164,25 @@ mod tests {
         assert_eq!(credentials, credentials2);
     }
 
+    #[test]
+    fn rotate_from_credentials() {
+        let credentials = RotatingCredentials::from_credentials(Credentials::new("abcd", "1234"));
+
+        let current = credentials.get();
+        assert_eq!(current.key(), "abcd");
+        assert_eq!(current.secret(), "1234");
+        assert_eq!(current.token(), None);
+        drop(current);
+
+        credentials.update_from(Credentials::new_with_token("dcba", "4321", "yxz"));
+
+        let current = credentials.get();
+        assert_eq!(current.key(), "dcba");
+        assert_eq!(current.secret(), "4321");
+        assert_eq!(current.token(), Some("yxz"));
+        drop(current);
+    }
+
     #[test]
     fn debug() {
         let credentials =
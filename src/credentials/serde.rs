@@ -79,16 +79,13 @@ impl Ec2SecurityCredentialsMetadataResponse {
         let key = mem::take(&mut self.key);
         let secret = mem::take(&mut self.secret);
         let token = mem::take(&mut self.token);
-        Credentials::new_with_token(key, secret, token)
+        Credentials::new_with_token_and_expiration(key, secret, token, self.expiration.assume_utc())
     }
 
     /// Update a [`RotatingCredentials`] with the credentials of this `Ec2SecurityCredentialsMetadataResponse`
     #[inline]
-    pub fn rotate_credentials(mut self, rotating: &RotatingCredentials) {
-        let key = mem::take(&mut self.key);
-        let secret = mem::take(&mut self.secret);
-        let token = mem::take(&mut self.token);
-        rotating.update(key, secret, Some(token));
+    pub fn rotate_credentials(self, rotating: &RotatingCredentials) {
+        rotating.update_from(self.into_credentials());
     }
 }
 
@@ -140,4 +137,27 @@ mod tests {
             "Ec2SecurityCredentialsMetadataResponse { key: \"some_access_key\", .. }"
         );
     }
+
+    #[test]
+    fn into_credentials() {
+        let json = r#"{
+    "Code" : "Success",
+    "LastUpdated" : "2020-12-28T16:47:50Z",
+    "Type" : "AWS-HMAC",
+    "AccessKeyId" : "some_access_key",
+    "SecretAccessKey" : "some_secret_key",
+    "Token" : "some_token",
+    "Expiration" : "2020-12-28T23:10:09Z"
+}"#;
+
+        let deserialized = Ec2SecurityCredentialsMetadataResponse::deserialize(json).unwrap();
+        let credentials = deserialized.into_credentials();
+        assert_eq!(credentials.key(), "some_access_key");
+        assert_eq!(credentials.secret(), "some_secret_key");
+        assert_eq!(credentials.token(), Some("some_token"));
+        assert_eq!(
+            credentials.expiration().unwrap().unix_timestamp(),
+            1_609_197_009
+        );
+    }
 }
@@ -0,0 +1,92 @@
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::OffsetDateTime;
+use zeroize::Zeroizing;
+
+use super::Credentials;
+
+/// The serialized form of [`Credentials`]
+///
+/// The secret is borrowed when serializing, and moved into the
+/// `Zeroizing` wrapper of [`Credentials`] without copies when deserializing.
+#[derive(Serialize, Deserialize)]
+struct CredentialsRepr<S> {
+    key: S,
+    secret: S,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    token: Option<S>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    expiration: Option<OffsetDateTime>,
+}
+
+impl Serialize for Credentials {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CredentialsRepr {
+            key: self.key(),
+            secret: self.secret(),
+            token: self.token(),
+            expiration: self.expiration,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Credentials {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CredentialsRepr::<String>::deserialize(deserializer)?;
+        Ok(Self {
+            key: repr.key,
+            secret: Zeroizing::new(repr.secret),
+            token: repr.token,
+            expiration: repr.expiration,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "full"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let credentials = Credentials::new_with_token("abcd", "1234", "xyz");
+
+        let json = serde_json::to_string(&credentials).unwrap();
+        assert_eq!(json, r#"{"key":"abcd","secret":"1234","token":"xyz"}"#);
+
+        let deserialized: Credentials = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, credentials);
+        assert_eq!(deserialized.secret(), "1234");
+        assert_eq!(deserialized.token(), Some("xyz"));
+        assert_eq!(
+            format!("{deserialized:?}"),
+            "Credentials { key: \"abcd\", .. }"
+        );
+    }
+
+    #[test]
+    fn json_expiration() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let expiration = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+        let credentials =
+            Credentials::new_with_token_and_expiration("abcd", "1234", "xyz", expiration);
+
+        let json = serde_json::to_string(&credentials).unwrap();
+        assert_eq!(
+            json,
+            r#"{"key":"abcd","secret":"1234","token":"xyz","expiration":"2013-05-24T00:00:00Z"}"#
+        );
+
+        let deserialized: Credentials = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.expiration(), Some(expiration));
+
+        let deserialized: Credentials =
+            serde_json::from_str(r#"{"key":"abcd","secret":"1234"}"#).unwrap();
+        assert_eq!(deserialized, Credentials::new("abcd", "1234"));
+    }
+}
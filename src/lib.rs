@@ -51,11 +51,14 @@
 )]
 #![forbid(unsafe_code)]
 
-pub use self::actions::S3Action;
-pub use self::bucket::{Bucket, BucketError, UrlStyle};
+pub use self::actions::{S3Action, S3ActionExt};
+pub use self::bucket::{Bucket, BucketError, ObjectUrlBuilder, Partition, UrlStyle};
 pub use self::credentials::Credentials;
 pub use self::map::Map;
 pub use self::method::Method;
+#[cfg(feature = "http")]
+pub use self::method::UnsupportedMethod;
+pub use self::presign_cache::PresignCache;
 
 pub mod actions;
 #[cfg(feature = "full")]
@@ -64,6 +67,7 @@ mod bucket;
 pub mod credentials;
 mod map;
 mod method;
+mod presign_cache;
 pub mod signing;
 pub(crate) mod sorting_iter;
 pub(crate) mod time_;
@@ -1,7 +1,13 @@
 use std::borrow::Cow;
 use std::fmt::{self, Debug};
+use std::iter::FromIterator;
+use std::slice;
 
 /// A map used for holding query string paramenters or headers
+///
+/// Use [`Map::insert`] for values that must only appear once, like the
+/// `max-keys` query parameter, and [`Map::append`] for multi-value headers,
+/// whose values are joined with `", "`.
 #[derive(Clone)]
 pub struct Map<'a> {
     inner: Vec<(Cow<'a, str>, Cow<'a, str>)>,
@@ -37,6 +43,43 @@ impl<'a> Map<'a> {
             .map_or(None, |i| self.inner.get(i).map(|kv| kv.1.as_ref()))
     }
 
+    /// Return `true` if this `Map` contains `key`
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.inner
+            .binary_search_by(|a| a.0.as_ref().cmp(key))
+            .is_ok()
+    }
+
+    /// Get the value of `key`, inserting the value returned by `f` if it's not present
+    ///
+    /// Unlike [`Map::insert`] and [`Map::append`], an existing value is never
+    /// changed, which makes it suitable for default values:
+    ///
+    /// ```
+    /// let mut map = rusty_s3::Map::new();
+    /// map.insert("content-type", "image/jpeg");
+    /// assert_eq!(map.get_or_insert_with("content-type", || "application/octet-stream"), "image/jpeg");
+    /// assert_eq!(map.get_or_insert_with("cache-control", || "no-cache"), "no-cache");
+    /// ```
+    pub fn get_or_insert_with<K, F, V>(&mut self, key: K, f: F) -> &str
+    where
+        K: Into<Cow<'a, str>>,
+        F: FnOnce() -> V,
+        V: Into<Cow<'a, str>>,
+    {
+        let key = key.into();
+
+        let i = match self.inner.binary_search_by(|a| a.0.cmp(&key)) {
+            Ok(i) => i,
+            Err(i) => {
+                self.inner.insert(i, (key, f().into()));
+                i
+            }
+        };
+        &self.inner[i].1
+    }
+
     /// Insert a new element in this `Map`
     ///
     /// If the `key` is already present, the `value` overwrites the existing value:
@@ -70,6 +113,38 @@ impl<'a> Map<'a> {
         }
     }
 
+    /// Set the value of `key` to a copy of `value`
+    ///
+    /// Like [`Map::insert`], but if the `key` is already present with an
+    /// owned value, its allocation is reused instead of being replaced:
+    ///
+    /// ```
+    /// let mut map = rusty_s3::Map::new();
+    /// map.set("k", "a");
+    /// assert_eq!(map.get("k"), Some("a"));
+    /// map.set("k", "b");
+    /// assert_eq!(map.get("k"), Some("b"));
+    /// ```
+    pub fn set<K>(&mut self, key: K, value: &str)
+    where
+        K: Into<Cow<'a, str>>,
+    {
+        let key = key.into();
+
+        match self.inner.binary_search_by(|a| a.0.cmp(&key)) {
+            Ok(i) => {
+                let old_value = &mut self.inner[i].1;
+                if let Cow::Owned(old_value) = old_value {
+                    old_value.clear();
+                    old_value.push_str(value);
+                } else {
+                    *old_value = Cow::Owned(value.to_owned());
+                }
+            }
+            Err(i) => self.inner.insert(i, (key, Cow::Owned(value.to_owned()))),
+        }
+    }
+
     /// Insert a new element in this `Map`
     ///
     /// If the `key` is already present, the `value` is appended to the existing value:
@@ -126,6 +201,53 @@ impl Debug for Map<'_> {
     }
 }
 
+impl<'a, K, V> FromIterator<(K, V)> for Map<'a>
+where
+    K: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    /// Collect an `Iterator` of elements into a `Map`
+    ///
+    /// Like [`Map::append`], the values of duplicate keys are joined:
+    ///
+    /// ```
+    /// let map: rusty_s3::Map<'_> = [("k", "a"), ("j", "c"), ("k", "b")].into_iter().collect();
+    /// assert_eq!(map.get("k"), Some("a, b"));
+    /// assert_eq!(map.get("j"), Some("c"));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.append(key, value);
+        }
+        map
+    }
+}
+
+impl<'a> IntoIterator for Map<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+    type IntoIter = std::vec::IntoIter<(Cow<'a, str>, Cow<'a, str>)>;
+
+    /// Move the elements out of the `Map`, sorted by key
+    ///
+    /// Owned keys and values are moved out without being copied.
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+type IterFn<'b, 'a> = fn(&'b (Cow<'a, str>, Cow<'a, str>)) -> (&'b str, &'b str);
+
+impl<'b, 'a> IntoIterator for &'b Map<'a> {
+    type Item = (&'b str, &'b str);
+    type IntoIter = std::iter::Map<slice::Iter<'b, (Cow<'a, str>, Cow<'a, str>)>, IterFn<'b, 'a>>;
+
+    /// Iterate over the elements of the `Map`, sorted by key
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter().map(|t| (t.0.as_ref(), t.1.as_ref()))
+    }
+}
+
 impl Default for Map<'_> {
     #[inline]
     fn default() -> Self {
@@ -139,6 +261,113 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn collect_merges_duplicates() {
+        let map: Map<'_> = [
+            ("content-type", "text/plain"),
+            ("cache-control", "no-cache"),
+            ("cache-control", "no-store"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("cache-control"), Some("no-cache, no-store"));
+        assert_eq!(map.get("content-type"), Some("text/plain"));
+
+        let mut collected = Vec::new();
+        for (key, value) in &map {
+            collected.push((key, value));
+        }
+        assert_eq!(
+            collected,
+            [
+                ("cache-control", "no-cache, no-store"),
+                ("content-type", "text/plain")
+            ]
+        );
+    }
+
+    #[test]
+    fn into_iter_moves_owned() {
+        let mut map = Map::new();
+        map.insert("content-type", "text/plain");
+        map.insert(String::from("x-amz-meta-a"), String::from("1"));
+        let key_ptr = map.iter().nth(1).unwrap().0.as_ptr();
+        let value_ptr = map.get("x-amz-meta-a").unwrap().as_ptr();
+
+        let drained = map
+            .into_iter()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect::<Vec<(String, String)>>();
+        assert_eq!(
+            drained,
+            [
+                ("content-type".to_owned(), "text/plain".to_owned()),
+                ("x-amz-meta-a".to_owned(), "1".to_owned())
+            ]
+        );
+        assert_eq!(drained[1].0.as_ptr(), key_ptr);
+        assert_eq!(drained[1].1.as_ptr(), value_ptr);
+    }
+
+    #[test]
+    fn insert_overwrites() {
+        let mut map = Map::new();
+        map.insert("max-keys", "100");
+        map.insert("max-keys", "500");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("max-keys"), Some("500"));
+    }
+
+    #[test]
+    fn append_concatenates() {
+        let mut map = Map::new();
+        map.append("cache-control", "no-cache");
+        map.append("cache-control", "no-store");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("cache-control"), Some("no-cache, no-store"));
+    }
+
+    #[test]
+    fn get_or_insert_with() {
+        let mut map = Map::new();
+        assert!(!map.contains_key("content-type"));
+        assert_eq!(
+            map.get_or_insert_with("content-type", || "application/octet-stream"),
+            "application/octet-stream"
+        );
+        assert!(map.contains_key("content-type"));
+
+        map.insert("cache-control", "no-cache");
+        let value = map.get_or_insert_with("cache-control", || -> String {
+            panic!("the value is already present")
+        });
+        assert_eq!(value, "no-cache");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("cache-control"), Some("no-cache"));
+        assert_eq!(map.get("content-type"), Some("application/octet-stream"));
+    }
+
+    #[test]
+    fn set_reuses_allocation() {
+        let mut map = Map::new();
+        map.set("continuation-token", "abcdefghijklmnopqrstuvwxyz");
+        let ptr = map.get("continuation-token").unwrap().as_ptr();
+
+        map.set("continuation-token", "zyxwvutsrqponmlkjihgfedcba");
+        assert_eq!(
+            map.get("continuation-token"),
+            Some("zyxwvutsrqponmlkjihgfedcba")
+        );
+        assert_eq!(map.get("continuation-token").unwrap().as_ptr(), ptr);
+
+        map.insert("prefix", "borrowed");
+        map.set("prefix", "owned");
+        assert_eq!(map.get("prefix"), Some("owned"));
+        assert_eq!(map.len(), 2);
+    }
+
     #[test]
     fn map() {
         let mut map = Map::new();
@@ -1,7 +1,9 @@
+#[cfg(feature = "http")]
+use std::error::Error as StdError;
 use std::fmt::{self, Display};
 
 /// The HTTP request method for an [`S3Action`](crate::actions::S3Action).
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Method {
     Head,
     Get,
@@ -37,6 +39,60 @@ impl Display for Method {
     }
 }
 
+#[cfg(feature = "http")]
+impl From<Method> for http::Method {
+    #[inline]
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Head => Self::HEAD,
+            Method::Get => Self::GET,
+            Method::Post => Self::POST,
+            Method::Put => Self::PUT,
+            Method::Delete => Self::DELETE,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<http::Method> for Method {
+    type Error = UnsupportedMethod;
+
+    fn try_from(method: http::Method) -> Result<Self, Self::Error> {
+        match method {
+            http::Method::HEAD => Ok(Self::Head),
+            http::Method::GET => Ok(Self::Get),
+            http::Method::POST => Ok(Self::Post),
+            http::Method::PUT => Ok(Self::Put),
+            http::Method::DELETE => Ok(Self::Delete),
+            method => Err(UnsupportedMethod(method)),
+        }
+    }
+}
+
+/// An [`http::Method`] which isn't used by any [`S3Action`](crate::actions::S3Action)
+#[cfg(feature = "http")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedMethod(http::Method);
+
+#[cfg(feature = "http")]
+impl UnsupportedMethod {
+    /// Get the method that couldn't be converted
+    #[must_use]
+    pub const fn method(&self) -> &http::Method {
+        &self.0
+    }
+}
+
+#[cfg(feature = "http")]
+impl Display for UnsupportedMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported method `{}`", self.0)
+    }
+}
+
+#[cfg(feature = "http")]
+impl StdError for UnsupportedMethod {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +114,28 @@ mod tests {
         assert_eq!(Method::Put.to_string(), "PUT");
         assert_eq!(Method::Delete.to_string(), "DELETE");
     }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn http_round_trip() {
+        for method in [
+            Method::Head,
+            Method::Get,
+            Method::Post,
+            Method::Put,
+            Method::Delete,
+        ] {
+            let http_method = http::Method::from(method);
+            assert_eq!(http_method.as_str(), method.to_str());
+            assert_eq!(Method::try_from(http_method), Ok(method));
+        }
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn http_unsupported() {
+        let err = Method::try_from(http::Method::PATCH).unwrap_err();
+        assert_eq!(err.method(), http::Method::PATCH);
+        assert_eq!(err.to_string(), "unsupported method `PATCH`");
+    }
 }
@@ -1,11 +1,12 @@
-use std::fmt;
+use std::fmt::{self, Write as _};
 
 use url::Url;
 
 use super::util::percent_encode;
 use crate::Method;
 
-const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+/// The `x-amz-content-sha256` header value of a request whose body isn't signed.
+pub const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
 
 pub fn canonical_request<'a, Q, H, S>(
     method: Method,
@@ -13,6 +14,7 @@ pub fn canonical_request<'a, Q, H, S>(
     query_string: Q,
     headers: H,
     signed_headers: S,
+    payload_hash: &str,
 ) -> String
 where
     Q: Iterator<Item = (&'a str, &'a str)>,
@@ -22,7 +24,7 @@ where
     let mut string = String::with_capacity(64);
     string.push_str(method.to_str());
     string.push('\n');
-    string.push_str(url.path());
+    canonical_uri(url.path(), &mut string);
     string.push('\n');
 
     canonical_query_string(query_string, &mut string).expect("String writer panicked");
@@ -37,11 +39,41 @@ where
 
     string.push('\n');
 
-    string.push_str(UNSIGNED_PAYLOAD);
+    string.push_str(payload_hash);
 
     string
 }
 
+/// Write the canonical URI of `path`
+///
+/// Every byte other than the unreserved characters and `/` is percent-encoded.
+/// S3 doesn't double encode paths, so existing escapes are kept, with their hex
+/// digits uppercased. Paths generated by [`Bucket::object_url`](crate::Bucket::object_url)
+/// are already canonical, but hand-built ones may contain characters like `=`,
+/// `@` or `:`, which the `url` crate doesn't encode.
+fn canonical_uri(path: &str, out: &mut String) {
+    let bytes = path.as_bytes();
+    let mut i = 0;
+    while let Some(&byte) = bytes.get(i) {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                out.push(char::from(byte));
+            }
+            b'%' if bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)) =>
+            {
+                out.push('%');
+                out.push(char::from(bytes[i + 1].to_ascii_uppercase()));
+                out.push(char::from(bytes[i + 2].to_ascii_uppercase()));
+                i += 2;
+            }
+            _ => write!(out, "%{byte:02X}").expect("String writer panicked"),
+        }
+        i += 1;
+    }
+}
+
 fn canonical_query_string<'a, Q>(query_string: Q, mut out: impl fmt::Write) -> fmt::Result
 where
     Q: Iterator<Item = (&'a str, &'a str)>,
@@ -67,7 +99,18 @@ where
     for (key, val) in headers {
         out.write_str(key)?;
         out.write_char(':')?;
-        out.write_str(val.trim())?;
+
+        // trim the value and collapse sequential whitespace into a single space
+        let mut first = true;
+        for part in val.split_ascii_whitespace() {
+            if first {
+                first = false;
+            } else {
+                out.write_char(' ')?;
+            }
+
+            out.write_str(part)?;
+        }
 
         out.write_char('\n')?;
     }
@@ -144,8 +187,66 @@ mod tests {
             .into_iter(),
             vec![("host", url.host_str().unwrap())].into_iter(),
             vec!["host"].into_iter(),
+            UNSIGNED_PAYLOAD,
         );
 
         assert_eq!(got, expected);
     }
+
+    #[test]
+    fn aws_put_object_example() {
+        let method = Method::Put;
+        let url = "https://examplebucket.s3.amazonaws.com/test%24file.text"
+            .parse()
+            .unwrap();
+        let payload_hash = "44ce7dd67c959e0d3524ffac1771dfbba87d2b6b4b4e99e42034a8b803f8b072";
+
+        let expected = concat!(
+            "PUT\n",
+            "/test%24file.text\n",
+            "\n",
+            "date:Fri, 24 May 2013 00:00:00 GMT\n",
+            "host:examplebucket.s3.amazonaws.com\n",
+            "x-amz-content-sha256:44ce7dd67c959e0d3524ffac1771dfbba87d2b6b4b4e99e42034a8b803f8b072\n",
+            "x-amz-date:20130524T000000Z\n",
+            "x-amz-storage-class:REDUCED_REDUNDANCY\n",
+            "\n",
+            "date;host;x-amz-content-sha256;x-amz-date;x-amz-storage-class\n",
+            "44ce7dd67c959e0d3524ffac1771dfbba87d2b6b4b4e99e42034a8b803f8b072",
+        );
+
+        let headers = [
+            ("date", "Fri, 24 May 2013 00:00:00 GMT"),
+            ("host", "examplebucket.s3.amazonaws.com"),
+            ("x-amz-content-sha256", payload_hash),
+            ("x-amz-date", "20130524T000000Z"),
+            ("x-amz-storage-class", "REDUCED_REDUNDANCY"),
+        ];
+
+        let got = canonical_request(
+            method,
+            &url,
+            std::iter::empty(),
+            headers.iter().copied(),
+            headers.iter().map(|(k, _)| *k),
+            payload_hash,
+        );
+
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn canonical_uri_reserved_characters() {
+        let mut out = String::new();
+        canonical_uri("/a=b@c:d/e%20f.txt", &mut out);
+        assert_eq!(out, "/a%3Db%40c%3Ad/e%20f.txt");
+
+        let mut out = String::new();
+        canonical_uri("/already%3dencoded+plus%2", &mut out);
+        assert_eq!(out, "/already%3Dencoded%2Bplus%252");
+
+        let mut out = String::new();
+        canonical_uri("/Az09-._~/", &mut out);
+        assert_eq!(out, "/Az09-._~/");
+    }
 }
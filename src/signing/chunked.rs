@@ -0,0 +1,181 @@
+//! Chunked upload signing, using `STREAMING-AWS4-HMAC-SHA256-PAYLOAD`
+//!
+//! Find out more about chunked uploads from the [AWS documentation][docs]
+//!
+//! [docs]: https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html
+
+use std::fmt::{self, Debug, Formatter};
+
+use sha2::{Digest as _, Sha256};
+use time::OffsetDateTime;
+use zeroize::Zeroizing;
+
+use super::signature::sign_string;
+use super::util::hex_encode;
+use crate::time_::{ISO8601, YYYYMMDD};
+
+/// The `x-amz-content-sha256` header value of a chunked upload.
+pub const STREAMING_PAYLOAD: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD";
+
+/// The `x-amz-content-sha256` header value of a chunked upload with unsigned
+/// chunks, terminated by a trailer.
+pub const STREAMING_UNSIGNED_PAYLOAD_TRAILER: &str = "STREAMING-UNSIGNED-PAYLOAD-TRAILER";
+
+/// The SHA-256 hash of an empty string
+const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Signs the chunks of a chunked upload
+///
+/// Every chunk signature is chained to the previous one, starting from
+/// the seed signature of the request. Chunks must therefore be signed in
+/// the same order in which they are sent, ending with a zero-length chunk.
+#[derive(Clone)]
+pub struct ChunkedSigner {
+    signing_key: Zeroizing<[u8; 32]>,
+    date: String,
+    scope: String,
+    previous_signature: String,
+}
+
+impl ChunkedSigner {
+    /// Construct a new `ChunkedSigner`
+    ///
+    /// `seed_signature` is the signature of the request carrying the chunks,
+    /// while `date`, `region`, `service` and `signing_key` must be the same ones used to sign it.
+    ///
+    /// # Panics
+    ///
+    /// If date format is invalid.
+    pub(crate) fn new(
+        seed_signature: String,
+        date: &OffsetDateTime,
+        region: &str,
+        service: &str,
+        signing_key: Zeroizing<[u8; 32]>,
+    ) -> Self {
+        let yyyymmdd = date.format(&YYYYMMDD).expect("invalid format");
+
+        Self {
+            signing_key,
+            date: date.format(&ISO8601).expect("invalid format"),
+            scope: format!("{yyyymmdd}/{region}/{service}/aws4_request"),
+            previous_signature: seed_signature,
+        }
+    }
+
+    /// Calculate the `chunk-signature` of the next `chunk`
+    pub fn sign_chunk(&mut self, chunk: &[u8]) -> String {
+        let hash = hex_encode(&Sha256::digest(chunk));
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-PAYLOAD\n{}\n{}\n{}\n{EMPTY_SHA256}\n{hash}",
+            self.date, self.scope, self.previous_signature
+        );
+
+        let signature = sign_string(&self.signing_key, &string_to_sign);
+        self.previous_signature.clone_from(&signature);
+        signature
+    }
+
+    /// Sign the next `chunk` and frame it, ready to be sent as part of the body
+    ///
+    /// The upload must be terminated by framing a zero-length chunk.
+    pub fn frame_chunk(&mut self, chunk: &[u8]) -> Vec<u8> {
+        let signature = self.sign_chunk(chunk);
+        frame(chunk, &signature)
+    }
+}
+
+impl Debug for ChunkedSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkedSigner")
+            .field("date", &self.date)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Frame `chunk` as `<hex-len>;chunk-signature=<signature>\r\n<chunk>\r\n`
+#[must_use]
+pub fn frame(chunk: &[u8], signature: &str) -> Vec<u8> {
+    let header = format!("{:x};chunk-signature={signature}\r\n", chunk.len());
+
+    let mut framed = Vec::with_capacity(header.len() + chunk.len() + 2);
+    framed.extend_from_slice(header.as_bytes());
+    framed.extend_from_slice(chunk);
+    framed.extend_from_slice(b"\r\n");
+    framed
+}
+
+/// Frame an unsigned `chunk` as `<hex-len>\r\n<chunk>\r\n`
+///
+/// Used by uploads using `STREAMING-UNSIGNED-PAYLOAD-TRAILER`, which
+/// must be terminated by [`unsigned_trailer`].
+#[must_use]
+pub fn frame_unsigned(chunk: &[u8]) -> Vec<u8> {
+    let header = format!("{:x}\r\n", chunk.len());
+
+    let mut framed = Vec::with_capacity(header.len() + chunk.len() + 2);
+    framed.extend_from_slice(header.as_bytes());
+    framed.extend_from_slice(chunk);
+    framed.extend_from_slice(b"\r\n");
+    framed
+}
+
+/// Frame the final zero-length chunk of an upload using
+/// `STREAMING-UNSIGNED-PAYLOAD-TRAILER`, followed by the trailer
+/// `<name>:<value>`
+///
+/// `name` must be the same header announced in `x-amz-trailer`.
+#[must_use]
+pub fn unsigned_trailer(name: &str, value: &str) -> Vec<u8> {
+    format!("0\r\n{name}:{value}\r\n\r\n").into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use time::OffsetDateTime;
+
+    use super::*;
+    use crate::signing::signature::signing_key;
+
+    #[test]
+    fn aws_example() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let date = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+
+        let secret = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+        let region = "us-east-1";
+        let seed_signature = "4f232c4386841ef735655705268965c44a0e4690baa4adea153f7db9fa80a0a9";
+
+        let signing_key = signing_key(&date, secret, region, "s3");
+        let mut signer =
+            ChunkedSigner::new(seed_signature.to_owned(), &date, region, "s3", signing_key);
+
+        let chunk1 = vec![b'a'; 64 * 1024];
+        let framed = signer.frame_chunk(&chunk1);
+        let header = "10000;chunk-signature=ad80c730a21e5b8d04586a2213dd63b9a0e99e0e2307b0ade35a65485a288648\r\n";
+        assert_eq!(&framed[..header.len()], header.as_bytes());
+        assert_eq!(&framed[header.len()..framed.len() - 2], chunk1.as_slice());
+        assert_eq!(&framed[framed.len() - 2..], b"\r\n");
+
+        let chunk2 = vec![b'a'; 1024];
+        let framed = signer.frame_chunk(&chunk2);
+        let header = "400;chunk-signature=0055627c9e194cb4542bae2aa5492e3c1575bbb81b612b7d234b86a503ef5497\r\n";
+        assert_eq!(&framed[..header.len()], header.as_bytes());
+        assert_eq!(framed.len(), header.len() + chunk2.len() + 2);
+
+        let framed = signer.frame_chunk(&[]);
+        let expected = "0;chunk-signature=b6c6ea8a5354eaf15b3cb7646744f4275b71ea724fed81ceb9323e279d449df9\r\n\r\n";
+        assert_eq!(String::from_utf8(framed).unwrap(), expected);
+    }
+
+    #[test]
+    fn unsigned_with_trailer() {
+        let mut body = frame_unsigned(b"Hello world");
+        body.extend(unsigned_trailer("x-amz-checksum-crc32", "i9aeUg=="));
+
+        let expected = "b\r\nHello world\r\n0\r\nx-amz-checksum-crc32:i9aeUg==\r\n\r\n";
+        assert_eq!(String::from_utf8(body).unwrap(), expected);
+    }
+}
@@ -0,0 +1,105 @@
+//! The steps of AWS Signature Version 4, for signing requests to other AWS services
+//!
+//! [`sign`](super::sign) combines them to presign S3 urls. Using them directly
+//! allows signing requests to services which need the signature in the
+//! `Authorization` header, or a different set of headers.
+//!
+//! ```rust
+//! # use rusty_s3::Method;
+//! use rusty_s3::signing::primitives::{canonical_request, signature, string_to_sign};
+//! use time::OffsetDateTime;
+//! use url::Url;
+//!
+//! // Sun, 30 Aug 2015 12:36:00 GMT
+//! let date = OffsetDateTime::from_unix_timestamp(1_440_938_160).unwrap();
+//! let url = Url::parse("https://example.amazonaws.com/").unwrap();
+//! let headers = [
+//!     ("host", "example.amazonaws.com"),
+//!     ("x-amz-date", "20150830T123600Z"),
+//! ];
+//!
+//! let canonical_request = canonical_request(
+//!     Method::Get,
+//!     &url,
+//!     [].into_iter(),
+//!     headers.into_iter(),
+//!     headers.iter().map(|&(name, _)| name),
+//!     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+//! );
+//! let string_to_sign = string_to_sign(&date, "us-east-1", "service", &canonical_request);
+//! let signature = signature(
+//!     &date,
+//!     "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+//!     "us-east-1",
+//!     "service",
+//!     &string_to_sign,
+//! );
+//! assert_eq!(
+//!     signature,
+//!     "5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+//! );
+//! ```
+
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::Method;
+
+/// Build the canonical request of a request to `url`
+///
+/// The path of `url` is used as the canonical URI, while its query string is
+/// ignored in favour of `query_string`, whose names and values are encoded.
+/// `query_string` must be sorted by name. `headers` must have lowercase names,
+/// be sorted by name and include `host`. `signed_headers` are the names of the
+/// headers which are part of the signature, usually the names of all `headers`.
+/// `payload_hash` is the lowercase hex encoded SHA-256 of the body,
+/// or [`UNSIGNED_PAYLOAD`](super::UNSIGNED_PAYLOAD).
+pub fn canonical_request<'a, Q, H, S>(
+    method: Method,
+    url: &Url,
+    query_string: Q,
+    headers: H,
+    signed_headers: S,
+    payload_hash: &str,
+) -> String
+where
+    Q: Iterator<Item = (&'a str, &'a str)>,
+    H: Iterator<Item = (&'a str, &'a str)>,
+    S: Iterator<Item = &'a str>,
+{
+    super::canonical_request::canonical_request(
+        method,
+        url,
+        query_string,
+        headers,
+        signed_headers,
+        payload_hash,
+    )
+}
+
+/// Build the string to sign of `canonical_request`, sent at `date`
+/// to `service` in `region`
+#[must_use]
+pub fn string_to_sign(
+    date: &OffsetDateTime,
+    region: &str,
+    service: &str,
+    canonical_request: &str,
+) -> String {
+    super::string_to_sign::string_to_sign(date, region, service, canonical_request)
+}
+
+/// Compute the lowercase hex encoded signature of `string_to_sign`
+///
+/// The signing key is derived from `secret`, `date`, `region` and `service`,
+/// which must be the same ones `string_to_sign` was built with.
+#[must_use]
+pub fn signature(
+    date: &OffsetDateTime,
+    secret: &str,
+    region: &str,
+    service: &str,
+    string_to_sign: &str,
+) -> String {
+    super::signature::signature(date, secret, region, service, string_to_sign)
+}
@@ -1,8 +1,10 @@
-use hmac::{Hmac, Mac as _};
+use hmac::digest::{KeyInit, Output};
+use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use time::OffsetDateTime;
-use zeroize::Zeroizing;
+use zeroize::{Zeroize as _, Zeroizing};
 
+use super::util::hex_encode;
 use crate::time_::YYYYMMDD;
 
 type HmacSha256 = Hmac<Sha256>;
@@ -11,8 +13,43 @@ pub fn signature(
     date: &OffsetDateTime,
     secret: &str,
     region: &str,
+    service: &str,
     string_to_sign: &str,
 ) -> String {
+    signature_with::<HmacSha256>(date, secret, region, service, string_to_sign)
+}
+
+/// Like [`signature`], but derives the signing key and signs
+/// `string_to_sign` with the HMAC `M` instead of HMAC-SHA256.
+pub fn signature_with<M: Mac + KeyInit>(
+    date: &OffsetDateTime,
+    secret: &str,
+    region: &str,
+    service: &str,
+    string_to_sign: &str,
+) -> String {
+    let mut signing_key = signing_key_with::<M>(date, secret, region, service);
+    let signature = sign_string_with::<M>(&signing_key, string_to_sign);
+    signing_key.as_mut_slice().zeroize();
+    signature
+}
+
+pub fn signing_key(
+    date: &OffsetDateTime,
+    secret: &str,
+    region: &str,
+    service: &str,
+) -> Zeroizing<[u8; 32]> {
+    Zeroizing::new(signing_key_with::<HmacSha256>(date, secret, region, service).into())
+}
+
+/// Like [`signing_key`], but derives the key with the HMAC `M`.
+pub fn signing_key_with<M: Mac + KeyInit>(
+    date: &OffsetDateTime,
+    secret: &str,
+    region: &str,
+    service: &str,
+) -> Output<M> {
     let yyyymmdd = date.format(&YYYYMMDD).expect("invalid format");
 
     let mut raw_date = String::with_capacity("AWS4".len() + secret.len());
@@ -20,28 +57,25 @@ pub fn signature(
     raw_date.push_str(secret);
     let raw_date = Zeroizing::new(raw_date);
 
-    let mut mac =
-        HmacSha256::new_from_slice(raw_date.as_bytes()).expect("HMAC can take keys of any size");
-    mac.update(yyyymmdd.as_bytes());
-    let date_key = mac.finalize().into_bytes();
-
-    let mut mac = HmacSha256::new_from_slice(&date_key).expect("HMAC can take keys of any size");
-    mac.update(region.as_bytes());
-    let date_region_key = mac.finalize().into_bytes();
+    let date_key = hmac::<M>(raw_date.as_bytes(), yyyymmdd.as_bytes());
+    let date_region_key = hmac::<M>(&date_key, region.as_bytes());
+    let date_region_service_key = hmac::<M>(&date_region_key, service.as_bytes());
+    hmac::<M>(&date_region_service_key, b"aws4_request")
+}
 
-    let mut mac =
-        HmacSha256::new_from_slice(&date_region_key).expect("HMAC can take keys of any size");
-    mac.update(b"s3");
-    let date_region_service_key = mac.finalize().into_bytes();
+pub fn sign_string(signing_key: &[u8; 32], string_to_sign: &str) -> String {
+    sign_string_with::<HmacSha256>(signing_key, string_to_sign)
+}
 
-    let mut mac = HmacSha256::new_from_slice(&date_region_service_key)
-        .expect("HMAC can take keys of any size");
-    mac.update(b"aws4_request");
-    let signing_key = mac.finalize().into_bytes();
+/// Like [`sign_string`], but signs with the HMAC `M`.
+pub fn sign_string_with<M: Mac + KeyInit>(signing_key: &[u8], string_to_sign: &str) -> String {
+    hex_encode(&hmac::<M>(signing_key, string_to_sign.as_bytes()))
+}
 
-    let mut mac = HmacSha256::new_from_slice(&signing_key).expect("HMAC can take keys of any size");
-    mac.update(string_to_sign.as_bytes());
-    format!("{:x}", mac.finalize().into_bytes())
+fn hmac<M: Mac + KeyInit>(key: &[u8], data: &[u8]) -> Output<M> {
+    let mut mac = <M as KeyInit>::new_from_slice(key).expect("HMAC can take keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes()
 }
 
 #[cfg(test)]
@@ -61,11 +95,63 @@ mod tests {
 
         let expected = "aeeed9bbccd4d02ee5c0109b86d86835f995330da4c265957d157751f604d404";
 
-        let got = signature(&date, secret, region, create_string_to_sign());
+        let got = signature(&date, secret, region, "s3", create_string_to_sign());
 
         assert_eq!(got, expected);
     }
 
+    #[test]
+    fn aws_put_object_example() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let date = OffsetDateTime::from_unix_timestamp(1369353600).unwrap();
+
+        let secret = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+        let region = "us-east-1";
+
+        let string_to_sign = concat!(
+            "AWS4-HMAC-SHA256\n",
+            "20130524T000000Z\n",
+            "20130524/us-east-1/s3/aws4_request\n",
+            "9e0e90d9c76de8fa5b200d8c849cd5b8dc7a3be3951ddb7f6a76b4158342019d"
+        );
+        let expected = "98ad721746da40c64f1a55b78f14c238d841ea1380cd77a1b5971af0ece108bd";
+
+        let got = signature(&date, secret, region, "s3", string_to_sign);
+
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn with_default_hmac() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let date = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+
+        let secret = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+        let string_to_sign = create_string_to_sign();
+
+        let expected = "aeeed9bbccd4d02ee5c0109b86d86835f995330da4c265957d157751f604d404";
+        let got = signature_with::<HmacSha256>(&date, secret, "us-east-1", "s3", string_to_sign);
+        assert_eq!(got, expected);
+
+        let got =
+            signature_with::<Hmac<sha2::Sha512>>(&date, secret, "us-east-1", "s3", string_to_sign);
+        assert_eq!(got.len(), 128);
+        assert_ne!(got[..64], *expected);
+    }
+
+    #[test]
+    fn lowercase_hex() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let date = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+
+        let secret = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+        let got = signature(&date, secret, "us-east-1", "s3", create_string_to_sign());
+
+        assert_eq!(got.len(), 64);
+        assert!(got.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')));
+        assert!(got.bytes().any(|b| b.is_ascii_lowercase()));
+    }
+
     fn create_string_to_sign() -> &'static str {
         concat!(
             "AWS4-HMAC-SHA256\n",
@@ -1,16 +1,35 @@
-use sha2::{Digest as _, Sha256};
+use sha2::{Digest, Sha256};
 use time::OffsetDateTime;
 
+use super::util::hex_encode;
 use crate::time_::{ISO8601, YYYYMMDD};
 
-pub fn string_to_sign(date: &OffsetDateTime, region: &str, canonical_request: &str) -> String {
+pub fn string_to_sign(
+    date: &OffsetDateTime,
+    region: &str,
+    service: &str,
+    canonical_request: &str,
+) -> String {
+    string_to_sign_with::<Sha256>(date, region, service, canonical_request)
+}
+
+/// Like [`string_to_sign`], but hashes the canonical request with `D`
+/// instead of SHA-256.
+///
+/// The algorithm line is always `AWS4-HMAC-SHA256`.
+pub fn string_to_sign_with<D: Digest>(
+    date: &OffsetDateTime,
+    region: &str,
+    service: &str,
+    canonical_request: &str,
+) -> String {
     let iso8601 = date.format(&ISO8601).expect("invalid format");
     let yyyymmdd = date.format(&YYYYMMDD).expect("invalid format");
 
-    let scope = format!("{yyyymmdd}/{region}/s3/aws4_request");
+    let scope = format!("{yyyymmdd}/{region}/{service}/aws4_request");
 
-    let hash = Sha256::digest(canonical_request.as_bytes());
-    format!("AWS4-HMAC-SHA256\n{iso8601}\n{scope}\n{hash:x}")
+    let hash = hex_encode(&D::digest(canonical_request.as_bytes()));
+    format!("AWS4-HMAC-SHA256\n{iso8601}\n{scope}\n{hash}")
 }
 
 #[cfg(test)]
@@ -34,11 +53,59 @@ mod tests {
             "3bfa292879f6447bbcda7001decf97f4a54dc650c8942174ae0a9121cf58ad04"
         );
 
-        let got = string_to_sign(&date, region, create_canonical_request());
+        let got = string_to_sign(&date, region, "s3", create_canonical_request());
 
         assert_eq!(got, expected);
     }
 
+    #[test]
+    fn aws_put_object_example() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let date = OffsetDateTime::from_unix_timestamp(1369353600).unwrap();
+
+        let region = "us-east-1";
+
+        let canonical_request = concat!(
+            "PUT\n",
+            "/test%24file.text\n",
+            "\n",
+            "date:Fri, 24 May 2013 00:00:00 GMT\n",
+            "host:examplebucket.s3.amazonaws.com\n",
+            "x-amz-content-sha256:44ce7dd67c959e0d3524ffac1771dfbba87d2b6b4b4e99e42034a8b803f8b072\n",
+            "x-amz-date:20130524T000000Z\n",
+            "x-amz-storage-class:REDUCED_REDUNDANCY\n",
+            "\n",
+            "date;host;x-amz-content-sha256;x-amz-date;x-amz-storage-class\n",
+            "44ce7dd67c959e0d3524ffac1771dfbba87d2b6b4b4e99e42034a8b803f8b072",
+        );
+        let expected = concat!(
+            "AWS4-HMAC-SHA256\n",
+            "20130524T000000Z\n",
+            "20130524/us-east-1/s3/aws4_request\n",
+            "9e0e90d9c76de8fa5b200d8c849cd5b8dc7a3be3951ddb7f6a76b4158342019d"
+        );
+
+        let got = string_to_sign(&date, region, "s3", canonical_request);
+
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn with_default_digest() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let date = OffsetDateTime::from_unix_timestamp(1_369_353_600).unwrap();
+
+        let canonical_request = create_canonical_request();
+        assert_eq!(
+            string_to_sign_with::<Sha256>(&date, "us-east-1", "s3", canonical_request),
+            string_to_sign(&date, "us-east-1", "s3", canonical_request)
+        );
+        assert_ne!(
+            string_to_sign_with::<sha2::Sha512>(&date, "us-east-1", "s3", canonical_request),
+            string_to_sign(&date, "us-east-1", "s3", canonical_request)
+        );
+    }
+
     fn create_canonical_request() -> &'static str {
         concat!(
             "GET\n",
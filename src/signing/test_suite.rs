@@ -0,0 +1,390 @@
+//! Test vectors from the [AWS SigV4 test suite][suite]
+//!
+//! Every case is checked against the canonical request, the string to sign
+//! and the final signature. Cases covering path normalization are omitted,
+//! since S3 doesn't normalize paths. Repeated headers are passed already
+//! joined by `,`, as the signer expects each header name to appear once.
+//!
+//! [suite]: https://docs.aws.amazon.com/general/latest/gr/signature-v4-test-suite.html
+
+use pretty_assertions::assert_eq;
+use time::OffsetDateTime;
+use url::Url;
+
+use super::canonical_request::canonical_request;
+use super::signature::signature;
+use super::string_to_sign::string_to_sign;
+use crate::Method;
+
+const SECRET: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+const REGION: &str = "us-east-1";
+const SERVICE: &str = "service";
+const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+struct Case {
+    name: &'static str,
+    method: Method,
+    path: &'static str,
+    query: &'static [(&'static str, &'static str)],
+    headers: &'static [(&'static str, &'static str)],
+    canonical_request: &'static str,
+    string_to_sign_hash: &'static str,
+    signature: &'static str,
+}
+
+const CASES: &[Case] = &[
+    Case {
+        name: "get-vanilla",
+        method: Method::Get,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "bb579772317eb040ac9ed261061d46c1f17a8133879d6129b6e1c25292927e63",
+        signature: "5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31",
+    },
+    Case {
+        name: "get-vanilla-empty-query-key",
+        method: Method::Get,
+        path: "/",
+        query: &[("Param1", "value1")],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "Param1=value1\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "1e24db194ed7d0eec2de28d7369675a243488e08526e8c1c73571282f7c517ab",
+        signature: "a67d582fa61cc504c4bae71f336f98b97f1ea3c7a6bfe1b6e45aec72011b9aeb",
+    },
+    Case {
+        name: "get-vanilla-query-order-key-case",
+        method: Method::Get,
+        path: "/",
+        query: &[("Param1", "value1"), ("Param2", "value2")],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "Param1=value1&Param2=value2\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "816cd5b414d056048ba4f7c5386d6e0533120fb1fcfa93762cf0fc39e2cf19e0",
+        signature: "b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500",
+    },
+    Case {
+        name: "get-vanilla-query-unreserved",
+        method: Method::Get,
+        path: "/",
+        query: &[("-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz", "-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz=-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "c30d4703d9f799439be92736156d47ccfb2d879ddf56f5befa6d1d6aab979177",
+        signature: "9c3e54bfcdf0b19771a7f523ee5669cdf59bc7cc0884027167c21bb143a40197",
+    },
+    Case {
+        name: "get-vanilla-utf8-query",
+        method: Method::Get,
+        path: "/",
+        query: &[("ሴ", "bar")],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "%E1%88%B4=bar\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "eb30c5bed55734080471a834cc727ae56beb50e5f39d1bff6d0d38cb192a7073",
+        signature: "2cdec8eed098649ff3a119c94853b13c643bcf08f8b0a1d91e12c9027818dd04",
+    },
+    Case {
+        name: "get-unreserved",
+        method: Method::Get,
+        path: "/-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "6a968768eefaa713e2a6b16b589a8ea192661f098f37349f4e2c0082757446f9",
+        signature: "07ef7494c76fa4850883e2b006601f940f8a34d404d0cfa977f52a65bbf5f24f",
+    },
+    Case {
+        name: "get-utf8",
+        method: Method::Get,
+        path: "/ሴ",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/%E1%88%B4\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "2a0a97d02205e45ce2e994789806b19270cfbbb0921b278ccf58f5249ac42102",
+        signature: "8318018e0b0f223aa2bbf98705b62bb787dc9c0e678f255a891fd03141be5d85",
+    },
+    Case {
+        name: "get-header-key-duplicate",
+        method: Method::Get,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("my-header1", "value2,value2,value1"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "my-header1:value2,value2,value1\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;my-header1;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "dc7f04a3abfde8d472b0ab1a418b741b7c67174dad1551b4117b15527fbe966c",
+        signature: "c9d5ea9f3f72853aea855b47ea873832890dbdd183b4468f858259531a5138ea",
+    },
+    Case {
+        name: "get-header-value-order",
+        method: Method::Get,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("my-header1", "value4,value1,value3,value2"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "my-header1:value4,value1,value3,value2\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;my-header1;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "31ce73cd3f3d9f66977ad3dd957dc47af14df92fcd8509f59b349e9137c58b86",
+        signature: "08c7e5a9acfcfeb3ab6b2185e75ce8b1deb5e634ec47601a50643f830c755c01",
+    },
+    Case {
+        name: "get-header-value-trim",
+        method: Method::Get,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("my-header1", " value1"),
+            ("my-header2", " \"a   b   c\""),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "GET\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "my-header1:value1\n",
+            "my-header2:\"a b c\"\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;my-header1;my-header2;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "a726db9b0df21c14f559d0a978e563112acb1b9e05476f0a6a1c7d68f28605c7",
+        signature: "acc3ed3afb60bb290fc8d2dd0098b9911fcaa05412b367055dee359757a9c736",
+    },
+    Case {
+        name: "post-vanilla",
+        method: Method::Post,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "POST\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "553f88c9e4d10fc9e109e2aeb65f030801b70c2f6468faca261d401ae622fc87",
+        signature: "5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b",
+    },
+    Case {
+        name: "post-vanilla-query",
+        method: Method::Post,
+        path: "/",
+        query: &[("Param1", "value1")],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "POST\n",
+            "/\n",
+            "Param1=value1\n",
+            "host:example.amazonaws.com\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "9d659678c1756bb3113e2ce898845a0a79dbbc57b740555917687f1b3340fbbd",
+        signature: "28038455d6de14eafc1f9222cf5aa6f1a96197d7deb8263271d420d138af7f11",
+    },
+    Case {
+        name: "post-header-key-sort",
+        method: Method::Post,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("my-header1", "value1"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "POST\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "my-header1:value1\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;my-header1;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "9368318c2967cf6de74404b30c65a91e8f6253e0a8659d6d5319f1a812f87d65",
+        signature: "c5410059b04c1ee005303aed430f6e6645f61f4dc9e1461ec8f8916fdf18852c",
+    },
+    Case {
+        name: "post-header-value-case",
+        method: Method::Post,
+        path: "/",
+        query: &[],
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("my-header1", "VALUE1"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        canonical_request: concat!(
+            "POST\n",
+            "/\n",
+            "\n",
+            "host:example.amazonaws.com\n",
+            "my-header1:VALUE1\n",
+            "x-amz-date:20150830T123600Z\n",
+            "\n",
+            "host;my-header1;x-amz-date\n",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        string_to_sign_hash: "d51ced243e649e3de6ef63afbbdcbca03131a21a7103a1583706a64618606a93",
+        signature: "cdbc9802e29d2942e5e10b5bccfdd67c5f22c7c4e8ae67b53629efa58b974b7d",
+    },
+];
+
+#[test]
+fn aws_sigv4_test_suite() {
+    // Sun, 30 Aug 2015 12:36:00 GMT
+    let date = OffsetDateTime::from_unix_timestamp(1_440_938_160).unwrap();
+
+    for case in CASES {
+        let url = Url::parse(&format!("https://example.amazonaws.com{}", case.path)).unwrap();
+
+        let got = canonical_request(
+            case.method,
+            &url,
+            case.query.iter().copied(),
+            case.headers.iter().copied(),
+            case.headers.iter().map(|(k, _)| *k),
+            EMPTY_SHA256,
+        );
+        assert_eq!(
+            got, case.canonical_request,
+            "canonical request of {}",
+            case.name
+        );
+
+        let expected = format!(
+            "AWS4-HMAC-SHA256\n20150830T123600Z\n20150830/us-east-1/service/aws4_request\n{}",
+            case.string_to_sign_hash
+        );
+        let got = string_to_sign(&date, REGION, SERVICE, case.canonical_request);
+        assert_eq!(got, expected, "string to sign of {}", case.name);
+
+        let got = signature(&date, SECRET, REGION, SERVICE, &expected);
+        assert_eq!(got, case.signature, "signature of {}", case.name);
+    }
+}
@@ -38,6 +38,18 @@ pub const FRAGMENT: &AsciiSet = &CONTROLS
 
 pub const FRAGMENT_SLASH: &AsciiSet = &FRAGMENT.add(b'/');
 
+/// Lowercase hex encode `bytes`
+pub fn hex_encode(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        hex.push(char::from(HEX[usize::from(byte >> 4)]));
+        hex.push(char::from(HEX[usize::from(byte & 0x0f)]));
+    }
+    hex
+}
+
 pub fn percent_encode(val: &str) -> impl Display + Into<Cow<'_, str>> + '_ {
     utf8_percent_encode(val, FRAGMENT_SLASH)
 }
@@ -54,5 +66,23 @@ where
     query_pairs.extend_pairs(params);
     drop(query_pairs);
 
+    // don't leave a trailing `?` if there were no params
+    if url.query() == Some("") {
+        url.set_query(None);
+    }
+
     url
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn hex_encode_lowercase() {
+        assert_eq!(hex_encode(&[]), "");
+        assert_eq!(hex_encode(&[0x00, 0x0f, 0xab, 0xff]), "000fabff");
+    }
+}
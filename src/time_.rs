@@ -1,5 +1,6 @@
 use time::format_description::FormatItem;
 use time::macros::format_description;
+use time::{OffsetDateTime, UtcOffset};
 
 /// The format used by the `Date` header.
 pub const ISO8601: &[FormatItem<'static>] =
@@ -12,3 +13,19 @@ pub const ISO8601_EXT: &[FormatItem<'static>] =
 
 /// The format used by the `x-amz-date` header.
 pub const YYYYMMDD: &[FormatItem<'static>] = format_description!("[year][month][day]");
+
+/// The RFC 1123 format used by HTTP dates, like the `If-Modified-Since` header.
+pub const RFC1123: &[FormatItem<'static>] = format_description!(
+    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+);
+
+/// Format `date` as an HTTP date, converting it to UTC first.
+///
+/// # Panics
+///
+/// If date format is invalid.
+pub fn http_date(date: OffsetDateTime) -> String {
+    date.to_offset(UtcOffset::UTC)
+        .format(&RFC1123)
+        .expect("invalid format")
+}
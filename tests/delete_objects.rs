@@ -3,7 +3,11 @@ use std::time::Duration;
 use reqwest::Client;
 use url::Url;
 
-use rusty_s3::actions::{ListObjectsV2, ListObjectsV2Response, ObjectIdentifier, S3Action as _};
+use rusty_s3::actions::{
+    BucketVersioningStatus, DeleteObjectsResponse, ListObjectsV2, ListObjectsV2Response,
+    ObjectIdentifier, S3Action as _,
+};
+use rusty_s3::{Bucket, Credentials};
 
 mod common;
 
@@ -54,6 +58,121 @@ async fn delete_objects() {
     assert!(list.contents.is_empty());
 }
 
+#[tokio::test]
+async fn delete_objects_versioned() {
+    let (bucket, credentials, client) = common::bucket().await;
+    enable_versioning(&bucket, &credentials, &client).await;
+
+    let first_version = put_object(&bucket, &credentials, &client, "versioned.txt", b"first").await;
+    let second_version =
+        put_object(&bucket, &credentials, &client, "versioned.txt", b"second").await;
+    assert_ne!(first_version, second_version);
+    let _ = put_object(&bucket, &credentials, &client, "marked.txt", b"marked").await;
+
+    let mut versioned = ObjectIdentifier::new("versioned.txt".to_owned());
+    versioned.version_id = Some(first_version.clone());
+    let objects = [versioned, ObjectIdentifier::new("marked.txt".to_owned())];
+    let action = bucket.delete_objects(Some(&credentials), objects.iter());
+    let url = action.sign(Duration::from_secs(60));
+    let (body, content_md5) = action.body_with_md5();
+    let resp = client
+        .post(url)
+        .header("Content-MD5", content_md5)
+        .body(body)
+        .send()
+        .await
+        .expect("send DeleteObjects")
+        .error_for_status()
+        .expect("DeleteObjects unexpected status code");
+    let text = resp.text().await.expect("DeleteObjects read response body");
+    let parsed = DeleteObjectsResponse::parse(&text).expect("DeleteObjects parse response");
+
+    assert!(parsed.errors.is_empty());
+    assert_eq!(parsed.deleted.len(), 2);
+
+    let versioned = parsed
+        .deleted
+        .iter()
+        .find(|deleted| deleted.key == "versioned.txt")
+        .expect("versioned.txt deleted");
+    assert_eq!(
+        versioned.version_id.as_deref(),
+        Some(first_version.as_str())
+    );
+    assert!(!versioned.delete_marker);
+
+    let marked = parsed
+        .deleted
+        .iter()
+        .find(|deleted| deleted.key == "marked.txt")
+        .expect("marked.txt deleted");
+    assert!(marked.delete_marker);
+    assert!(marked.delete_marker_version_id.is_some());
+
+    // only the deleted version is gone
+    let action = bucket.get_object(Some(&credentials), "versioned.txt");
+    let url = action.sign(Duration::from_secs(60));
+    let resp = client
+        .get(url)
+        .send()
+        .await
+        .expect("send GetObject")
+        .error_for_status()
+        .expect("GetObject unexpected status code");
+    let bytes = resp.bytes().await.expect("GetObject read response body");
+    assert_eq!(bytes.as_ref(), b"second");
+
+    let list_url = bucket
+        .list_objects_v2(Some(&credentials))
+        .sign(Duration::from_secs(60));
+    let list = get_objects_list(&client, list_url).await;
+    let keys = list
+        .contents
+        .iter()
+        .map(|content| content.key.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, ["versioned.txt"]);
+}
+
+async fn enable_versioning(bucket: &Bucket, credentials: &Credentials, client: &Client) {
+    let action = bucket.put_bucket_versioning(Some(credentials), BucketVersioningStatus::Enabled);
+    let url = action.sign(Duration::from_secs(60));
+    client
+        .put(url)
+        .body(action.body())
+        .send()
+        .await
+        .expect("send PutBucketVersioning")
+        .error_for_status()
+        .expect("PutBucketVersioning unexpected status code");
+}
+
+async fn put_object(
+    bucket: &Bucket,
+    credentials: &Credentials,
+    client: &Client,
+    key: &str,
+    body: &'static [u8],
+) -> String {
+    let action = bucket.put_object(Some(credentials), key);
+    let url = action.sign(Duration::from_secs(60));
+    let resp = client
+        .put(url)
+        .body(body)
+        .send()
+        .await
+        .expect("send PutObject")
+        .error_for_status()
+        .expect("PutObject unexpected status code");
+
+    resp.headers()
+        .get("x-amz-version-id")
+        .expect("x-amz-version-id header")
+        .to_str()
+        .expect("x-amz-version-id to_str()")
+        .to_owned()
+}
+
 async fn get_objects_list(client: &Client, url: Url) -> ListObjectsV2Response {
     let resp = client
         .get(url)
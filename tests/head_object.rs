@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use rusty_s3::actions::{HeadObjectResponse, S3Action as _};
+
+mod common;
+
+#[tokio::test]
+async fn head_object_metadata() {
+    let (bucket, credentials, client) = common::bucket().await;
+
+    let body = vec![b'r'; 1024];
+
+    let mut action = bucket.put_object(Some(&credentials), "duck.jpg");
+    action.headers_mut().insert("content-type", "image/jpeg");
+    action.with_metadata("animal", "duck");
+    action.with_metadata("color", "yellow");
+    let url = action.sign(Duration::from_secs(60));
+    client
+        .put(url)
+        .header("content-type", "image/jpeg")
+        .header("x-amz-meta-animal", "duck")
+        .header("x-amz-meta-color", "yellow")
+        .body(body.clone())
+        .send()
+        .await
+        .expect("send PutObject")
+        .error_for_status()
+        .expect("PutObject unexpected status code");
+
+    let action = bucket.head_object(Some(&credentials), "duck.jpg");
+    let url = action.sign(Duration::from_secs(60));
+    let resp = client
+        .head(url)
+        .send()
+        .await
+        .expect("send HeadObject")
+        .error_for_status()
+        .expect("HeadObject unexpected status code");
+
+    let parsed = HeadObjectResponse::from_headers(resp.headers().iter().map(|(name, value)| {
+        (
+            name.as_str(),
+            value.to_str().expect("header value to_str()"),
+        )
+    }));
+
+    assert_eq!(parsed.content_length, Some(1024));
+    assert_eq!(parsed.content_type.as_deref(), Some("image/jpeg"));
+    assert!(parsed.etag.is_some());
+    assert_eq!(parsed.metadata.len(), 2);
+    assert_eq!(parsed.metadata["animal"], "duck");
+    assert_eq!(parsed.metadata["color"], "yellow");
+}
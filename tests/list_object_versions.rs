@@ -0,0 +1,57 @@
+#![cfg(feature = "full")]
+
+use std::time::Duration;
+
+use rusty_s3::actions::{ListObjectVersions, S3Action as _};
+
+mod common;
+
+#[tokio::test]
+async fn paginate_encoded_keys() {
+    let (bucket, credentials, client) = common::bucket().await;
+
+    let keys = ["a b.txt", "a c.txt", "a+d.txt"];
+    for key in keys {
+        let action = bucket.put_object(Some(&credentials), key);
+        let url = action.sign(Duration::from_secs(60));
+        client
+            .put(url)
+            .body(key.as_bytes().to_vec())
+            .send()
+            .await
+            .expect("send PutObject")
+            .error_for_status()
+            .expect("PutObject unexpected status code");
+    }
+
+    let mut action = bucket.list_object_versions(Some(&credentials));
+    action.with_max_keys(1);
+
+    let mut listed = Vec::new();
+    let mut next = Some(action.clone());
+    while let Some(action) = next.take() {
+        let url = action.sign(Duration::from_secs(60));
+        let resp = client
+            .get(url)
+            .send()
+            .await
+            .expect("send ListObjectVersions")
+            .error_for_status()
+            .expect("ListObjectVersions unexpected status code");
+        let text = resp
+            .text()
+            .await
+            .expect("ListObjectVersions read response body");
+        let page =
+            ListObjectVersions::parse_response(&text).expect("ListObjectVersions parse response");
+
+        assert!(page.versions.len() <= 1);
+        listed.extend(page.versions.iter().map(|version| version.key.clone()));
+
+        if let Some(key_marker) = &page.next_key_marker {
+            next = Some(action.next_page(key_marker, page.next_version_id_marker.as_deref()));
+        }
+    }
+
+    assert_eq!(listed, keys);
+}
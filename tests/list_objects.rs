@@ -0,0 +1,128 @@
+#![cfg(feature = "full")]
+
+use std::time::Duration;
+
+use rusty_s3::actions::list_objects_v2::{immediate_children, ListObjectsChild};
+use rusty_s3::actions::{ListObjectsV2, ListObjectsV2Paginator, S3Action as _};
+use rusty_s3::signing::presign_url;
+use rusty_s3::{Map, Method};
+
+mod common;
+
+#[tokio::test]
+async fn paginate_delimiter() {
+    let (bucket, credentials, client) = common::bucket().await;
+
+    let keys = ["a/1.txt", "a/2.txt", "b/1.txt", "c.txt", "d.txt"];
+    for key in keys {
+        let action = bucket.put_object(Some(&credentials), key);
+        let url = action.sign(Duration::from_secs(60));
+        client
+            .put(url)
+            .body(key.as_bytes().to_vec())
+            .send()
+            .await
+            .expect("send PutObject")
+            .error_for_status()
+            .expect("PutObject unexpected status code");
+    }
+
+    let mut action = bucket.list_objects_v2(Some(&credentials));
+    action.with_delimiter("/");
+    action.with_max_keys(1);
+    let mut paginator = ListObjectsV2Paginator::new(action);
+
+    let mut pages = Vec::new();
+    while let Some(action) = paginator.next_action() {
+        let url = action.sign(Duration::from_secs(60));
+        let resp = client
+            .get(url)
+            .send()
+            .await
+            .expect("send ListObjectsV2")
+            .error_for_status()
+            .expect("ListObjectsV2 unexpected status code");
+        let text = resp.text().await.expect("ListObjectsV2 read response body");
+        let page = ListObjectsV2::parse_response(&text).expect("ListObjectsV2 parse response");
+
+        assert!(page.contents.len() + page.common_prefixes.len() <= 1);
+        paginator.advance(&page);
+        pages.push(page);
+    }
+
+    // 2 common prefixes and 2 objects, at most one per page
+    assert!(pages.len() >= 4);
+
+    let children = immediate_children("", &pages);
+    let keys = children
+        .iter()
+        .map(ListObjectsChild::key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, ["a/", "b/", "c.txt", "d.txt"]);
+
+    assert!(matches!(children[0], ListObjectsChild::Prefix(_)));
+    assert!(matches!(children[1], ListObjectsChild::Prefix(_)));
+    match children[2] {
+        ListObjectsChild::Object(content) => assert_eq!(content.size, 5),
+        ListObjectsChild::Prefix(_) => panic!("expected an object"),
+    }
+}
+
+#[tokio::test]
+async fn anonymous_public_bucket() {
+    let (bucket, credentials, client) = common::bucket().await;
+
+    let action = bucket.put_object(Some(&credentials), "public.txt");
+    let url = action.sign(Duration::from_secs(60));
+    client
+        .put(url)
+        .body("public")
+        .send()
+        .await
+        .expect("send PutObject")
+        .error_for_status()
+        .expect("PutObject unexpected status code");
+
+    // allow anyone to list the bucket and read its objects
+    let policy = format!(
+        r#"{{"Version":"2012-10-17","Statement":[{{"Effect":"Allow","Principal":{{"AWS":["*"]}},"Action":["s3:ListBucket"],"Resource":["arn:aws:s3:::{0}"]}},{{"Effect":"Allow","Principal":{{"AWS":["*"]}},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::{0}/*"]}}]}}"#,
+        bucket.name()
+    );
+    let mut url = bucket.base_url().clone();
+    url.set_query(Some("policy"));
+    let url = presign_url(
+        url,
+        &credentials,
+        bucket.region(),
+        bucket.service(),
+        Method::Put,
+        Duration::from_secs(60),
+        &Map::new(),
+    );
+    client
+        .put(url)
+        .body(policy)
+        .send()
+        .await
+        .expect("send PutBucketPolicy")
+        .error_for_status()
+        .expect("PutBucketPolicy unexpected status code");
+
+    let action = bucket.list_objects_v2(None);
+    let url = action.sign(Duration::from_secs(60));
+    assert!(url
+        .query_pairs()
+        .all(|(name, _)| !name.starts_with("X-Amz-")));
+    let resp = client
+        .get(url)
+        .send()
+        .await
+        .expect("send ListObjectsV2")
+        .error_for_status()
+        .expect("ListObjectsV2 unexpected status code");
+    let text = resp.text().await.expect("ListObjectsV2 read response body");
+    let parsed = ListObjectsV2::parse_response(&text).expect("ListObjectsV2 parse response");
+
+    assert_eq!(parsed.contents.len(), 1);
+    assert_eq!(parsed.contents[0].key, "public.txt");
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use rusty_s3::actions::S3Action as _;
+
+mod common;
+
+#[tokio::test]
+async fn unicode_and_spaces() {
+    let (bucket, credentials, client) = common::bucket().await;
+
+    let keys = ["hello world.txt", "naïve/dossier.txt", "emoji 🦆.bin"];
+    for (key, byte) in keys.into_iter().zip(b'a'..) {
+        let body = vec![byte; 1024];
+
+        let action = bucket.put_object(Some(&credentials), key);
+        let url = action.sign(Duration::from_secs(60));
+        client
+            .put(url)
+            .body(body.clone())
+            .send()
+            .await
+            .expect("send PutObject")
+            .error_for_status()
+            .expect("PutObject unexpected status code");
+
+        let action = bucket.head_object(Some(&credentials), key);
+        let url = action.sign(Duration::from_secs(60));
+        let resp = client
+            .head(url)
+            .send()
+            .await
+            .expect("send HeadObject")
+            .error_for_status()
+            .expect("HeadObject unexpected status code");
+
+        let content_length = resp
+            .headers()
+            .get("content-length")
+            .expect("Content-Length header")
+            .to_str()
+            .expect("Content-Length to_str()");
+        assert_eq!(content_length, body.len().to_string());
+
+        let action = bucket.get_object(Some(&credentials), key);
+        let url = action.sign(Duration::from_secs(60));
+        let resp = client
+            .get(url)
+            .send()
+            .await
+            .expect("send GetObject")
+            .error_for_status()
+            .expect("GetObject unexpected status code");
+        let bytes = resp.bytes().await.expect("GetObject read response body");
+
+        assert_eq!(body, bytes);
+    }
+}